@@ -503,8 +503,20 @@ mod tests {
         let response = multi_call_handler.call::<(u64, [u64; 2])>().await?;
         // ANCHOR_END: multi_contract_call_response
 
+        // ANCHOR: multi_call_per_call_responses
+        let array_call = &response.calls[1];
+        let array_from_call: [u64; 2] = array_call.decode_value()?;
+        let array_call_logs = array_call.get_logs()?;
+        let array_call_gas_used = array_call.gas_used;
+        // ANCHOR_END: multi_call_per_call_responses
+
         assert_eq!(counter, 42);
         assert_eq!(array, [42; 2]);
+        assert_eq!(array_from_call, [42; 2]);
+        assert!(array_call_logs.is_empty());
+        assert!(array_call_gas_used.is_some());
+        assert_eq!(response.calls.len(), 2);
+        assert!(response.calls.iter().all(|call| call.gas_used.is_some()));
 
         Ok(())
     }
@@ -1,24 +1,34 @@
 use fuels_core::code_gen::abigen::Abigen;
+use fuels_types::errors::Error;
 use inflector::Inflector;
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
 use rand::prelude::{Rng, SeedableRng, StdRng};
-use std::{ops::Deref, path::Path};
+use setup_contract_test::TestSetup;
+use std::{
+    env,
+    ops::Deref,
+    path::{Path, PathBuf},
+};
 use syn::{
+    braced,
     parse::{Parse, ParseStream, Result as ParseResult},
-    parse_macro_input, Ident, LitStr, Token,
+    parse_macro_input,
+    punctuated::Punctuated,
+    Ident, LitBool, LitStr, Token,
 };
 
+mod setup_contract_test;
+
 /// Abigen proc macro definition and helper functions/types.
 #[proc_macro]
 pub fn abigen(input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(input as Spanned<ContractArgs>);
 
-    Abigen::new(&args.name, &args.abi)
-        .unwrap()
-        .expand_contract()
-        .unwrap()
+    new_abigen(&args)
+        .and_then(|abigen| abigen.expand_contract())
+        .unwrap_or_else(|err| compile_error(args.span(), err))
         .into()
 }
 
@@ -27,10 +37,20 @@ pub fn abigen(input: TokenStream) -> TokenStream {
 pub fn script_abigen(input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(input as Spanned<ContractArgs>);
 
-    Abigen::new(&args.name, &args.abi)
-        .unwrap()
-        .expand_script()
-        .unwrap()
+    new_abigen(&args)
+        .and_then(|abigen| abigen.expand_script())
+        .unwrap_or_else(|err| compile_error(args.span(), err))
+        .into()
+}
+
+/// Abigen proc macro definition and helper functions/types for predicates
+#[proc_macro]
+pub fn predicate_abigen(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as Spanned<ContractArgs>);
+
+    new_abigen(&args)
+        .and_then(|abigen| abigen.expand_predicate())
+        .unwrap_or_else(|err| compile_error(args.span(), err))
         .into()
 }
 
@@ -38,14 +58,43 @@ pub fn script_abigen(input: TokenStream) -> TokenStream {
 pub fn wasm_abigen(input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(input as Spanned<ContractArgs>);
 
-    Abigen::new(&args.name, &args.abi)
-        .unwrap()
-        .no_std()
-        .expand_contract()
-        .unwrap()
+    new_abigen(&args)
+        .and_then(|abigen| abigen.no_std().expand_contract())
+        .unwrap_or_else(|err| compile_error(args.span(), err))
         .into()
 }
 
+/// Reports `err` as a compile error pointing at `span`, usually the one of the
+/// ABI or project path, instead of panicking.
+fn compile_error(span: Span, err: impl std::fmt::Display) -> proc_macro2::TokenStream {
+    syn::Error::new(span, err).to_compile_error()
+}
+
+/// Creates an `Abigen` for the given arguments, registering any user supplied
+/// type mappings and forc project artifacts.
+fn new_abigen(args: &ContractArgs) -> Result<Abigen, Error> {
+    let mut abigen = args.type_paths.iter().try_fold(
+        Abigen::new(&args.name, &args.abi)?,
+        |abigen, (type_field, rust_path)| abigen.with_type_path(type_field, rust_path),
+    )?;
+
+    if let Some((bin_path, storage_path)) = &args.artifact_paths {
+        abigen = abigen.with_artifact_paths(bin_path, storage_path);
+    }
+    if args.embed_bytecode {
+        abigen = abigen.embed_bytecode();
+    }
+    match &args.abi_trait {
+        Some(AbiTraitArg::Define(trait_name)) => abigen = abigen.with_abi_trait(trait_name)?,
+        Some(AbiTraitArg::Implement(trait_path)) => {
+            abigen = abigen.implement_abi_trait(trait_path)?
+        }
+        None => {}
+    }
+
+    Ok(abigen)
+}
+
 /// This proc macro is used to reduce the amount of boilerplate code in integration tests.
 /// When expanded, the proc macro will: launch a local provider, generate one wallet,
 /// deploy the selected contract and create a contract instance.
@@ -54,43 +103,56 @@ pub fn wasm_abigen(input: TokenStream) -> TokenStream {
 /// The same contract can be deployed multiple times as the macro uses deployment with salt.
 /// However, if you need to have a shared wallet between macros, the first macro must set the
 /// wallet name to `wallet`. The other ones must set the wallet name to `None`.
+///
+/// To set up several contracts, scripts and predicates at once, the macro
+/// also accepts a list of commands, see the `setup_contract_test` module.
 #[proc_macro]
 pub fn setup_contract_test(input: TokenStream) -> TokenStream {
-    let args = parse_macro_input!(input as Spanned<ContractTestArgs>);
+    match parse_macro_input!(input as ContractTestInput) {
+        ContractTestInput::Commands(setup) => setup
+            .expand()
+            .unwrap_or_else(syn::Error::into_compile_error)
+            .into(),
+        ContractTestInput::SingleContract(args) => setup_single_contract_test(args),
+    }
+}
 
-    let abs_forc_dir = Path::new(&args.project_path)
-        .canonicalize()
-        .unwrap_or_else(|_| {
-            panic!(
-                "Unable to canonicalize forc project path: {}. Make sure the path is valid!",
-                &args.project_path
-            )
-        });
-
-    let forc_project_name = abs_forc_dir
-        .file_name()
-        .expect("failed to get project name")
-        .to_str()
-        .expect("failed to convert project name to string");
-
-    let compiled_file_path = |suffix: &str, desc: &str| {
-        abs_forc_dir
-            .join(["out/debug/", forc_project_name, suffix].concat())
-            .to_str()
-            .unwrap_or_else(|| panic!("could not join path for {desc}"))
-            .to_string()
-    };
+/// The two forms accepted by `setup_contract_test!`.
+enum ContractTestInput {
+    Commands(Box<TestSetup>),
+    SingleContract(Spanned<ContractTestArgs>),
+}
 
-    let abi_path = compiled_file_path("-abi.json", "the ABI file");
-    let bin_path = compiled_file_path(".bin", "the binary file");
-    let storage_path = compiled_file_path("-storage_slots.json", "the storage slots file");
+impl Parse for ContractTestInput {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        if setup_contract_test::is_command_input(input) {
+            Ok(ContractTestInput::Commands(input.parse()?))
+        } else {
+            Ok(ContractTestInput::SingleContract(input.parse()?))
+        }
+    }
+}
+
+fn setup_single_contract_test(args: Spanned<ContractTestArgs>) -> TokenStream {
+    let ForcProjectPaths {
+        abi_path,
+        bin_path,
+        storage_path,
+    } = match ForcProjectPaths::new(Path::new(&args.project_path), "debug") {
+        Ok(paths) => paths,
+        Err(msg) => return compile_error(args.span(), msg).into(),
+    };
 
     let contract_struct_name = args.instance_name.to_class_case();
-    let mut abigen_token_stream: TokenStream = Abigen::new(&contract_struct_name, abi_path)
-        .unwrap()
-        .expand_contract()
-        .unwrap()
-        .into();
+    let mut abigen_token_stream: TokenStream = match Abigen::new(&contract_struct_name, abi_path)
+        .and_then(|abigen| {
+            abigen
+                .with_artifact_paths(&bin_path, &storage_path)
+                .expand_contract()
+        }) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => return compile_error(args.span(), err).into(),
+    };
 
     // Generate random salt for contract deployment
     let mut rng = StdRng::from_entropy();
@@ -111,20 +173,13 @@ pub fn setup_contract_test(input: TokenStream) -> TokenStream {
     };
 
     let contract_deploy_token_stream: TokenStream = quote! {
-        let #contract_instance_name = #contract_struct_name::new(
-            Contract::deploy_with_parameters(
-                #bin_path,
-                &#wallet_name,
-                TxParameters::default(),
-                StorageConfiguration::with_storage_path(Some(
-                    #storage_path.to_string(),
-                )),
-                Salt::from([#(#salt),*]),
-            )
-            .await
-            .expect("Failed to deploy the contract"),
-            #wallet_name.clone(),
-        );
+        let #contract_instance_name = #contract_struct_name::deploy_with_salt(
+            &#wallet_name,
+            DeployConfig::default(),
+            Salt::from([#(#salt),*]),
+        )
+        .await
+        .expect("Failed to deploy the contract");
     }
     .into();
 
@@ -158,7 +213,6 @@ struct Spanned<T>(Span, T);
 
 impl<T> Spanned<T> {
     /// Retrieves the captured `Span` information for the parsed data.
-    #[allow(dead_code)]
     pub fn span(&self) -> Span {
         self.0
     }
@@ -183,6 +237,19 @@ impl<T> Deref for Spanned<T> {
 pub(crate) struct ContractArgs {
     name: String,
     abi: String,
+    type_paths: Vec<(String, String)>,
+    /// The binary and storage slots paths, when the ABI comes from a forc
+    /// project.
+    artifact_paths: Option<(String, String)>,
+    embed_bytecode: bool,
+    abi_trait: Option<AbiTraitArg>,
+}
+
+/// The trait to generate, or to implement, for the contract instance.
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+pub(crate) enum AbiTraitArg {
+    Define(String),
+    Implement(String),
 }
 
 impl ParseInner for ContractArgs {
@@ -193,15 +260,220 @@ impl ParseInner for ContractArgs {
         // skip the comma
         input.parse::<Token![,]>()?;
 
-        let (span, abi) = {
+        // The ABI is either given directly, as a path or as a JSON string, or
+        // read from a forc project through the `project` option.
+        let mut abi = if input.peek(LitStr) {
             let literal = input.parse::<LitStr>()?;
-            (literal.span(), literal.value())
+            Some((literal.span(), literal.value()))
+        } else {
+            None
         };
-        if !input.is_empty() {
-            input.parse::<Token![,]>()?;
+        let mut project: Option<(Span, String)> = None;
+        let mut profile: Option<(Span, String)> = None;
+        let mut embed_bytecode: Option<(Span, bool)> = None;
+        let mut type_paths = vec![];
+        let mut abi_trait: Option<AbiTraitArg> = None;
+
+        let mut is_first_option = abi.is_none();
+        while !input.is_empty() {
+            if !is_first_option {
+                input.parse::<Token![,]>()?;
+                if input.is_empty() {
+                    break;
+                }
+            }
+            is_first_option = false;
+
+            let option = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            match option.to_string().as_str() {
+                "project" => {
+                    let literal = input.parse::<LitStr>()?;
+                    project = Some((literal.span(), literal.value()));
+                }
+                "profile" => {
+                    let literal = input.parse::<LitStr>()?;
+                    profile = Some((literal.span(), literal.value()));
+                }
+                "embed_bytecode" => {
+                    let literal = input.parse::<LitBool>()?;
+                    embed_bytecode = Some((literal.span(), literal.value()));
+                }
+                "abi_trait" | "impl_abi_trait" if abi_trait.is_some() => {
+                    return Err(syn::Error::new(
+                        option.span(),
+                        "only one of `abi_trait` or `impl_abi_trait` can be given",
+                    ))
+                }
+                "abi_trait" => {
+                    let trait_name = input.parse::<Ident>()?;
+                    abi_trait = Some(AbiTraitArg::Define(trait_name.to_string()));
+                }
+                "impl_abi_trait" => {
+                    let trait_path = input.parse::<syn::Path>()?;
+                    abi_trait = Some(AbiTraitArg::Implement(quote! { #trait_path }.to_string()));
+                }
+                "types" => {
+                    let content;
+                    braced!(content in input);
+                    type_paths = Punctuated::<TypePath, Token![,]>::parse_terminated(&content)?
+                        .into_iter()
+                        .map(|type_path| (type_path.type_field, type_path.rust_path))
+                        .collect();
+                }
+                other => {
+                    return Err(syn::Error::new(
+                        option.span(),
+                        format!(
+                            "unknown abigen option `{other}`, expected one of `project`, \
+                             `profile`, `embed_bytecode`, `abi_trait`, `impl_abi_trait` or \
+                             `types`"
+                        ),
+                    ))
+                }
+            }
+        }
+
+        let mut artifact_paths = None;
+        match (&abi, project) {
+            (Some(_), Some((span, _))) => {
+                return Err(syn::Error::new(
+                    span,
+                    "`project` cannot be used together with an ABI path or string",
+                ))
+            }
+            (None, None) => {
+                return Err(input.error("expected an ABI path or string, or a `project` option"))
+            }
+            (None, Some((span, project_path))) => {
+                let profile = profile
+                    .take()
+                    .map(|(_, profile)| profile)
+                    .unwrap_or_else(|| "debug".to_string());
+                let paths = ForcProjectPaths::new(&manifest_relative(&project_path), &profile)
+                    .map_err(|msg| syn::Error::new(span, msg))?;
+
+                abi = Some((span, paths.abi_path));
+                artifact_paths = Some((paths.bin_path, paths.storage_path));
+            }
+            (Some(_), None) => {}
+        }
+        if let Some((span, _)) = profile {
+            return Err(syn::Error::new(
+                span,
+                "`profile` can only be used together with `project`",
+            ));
         }
+        let embed_bytecode = match embed_bytecode {
+            Some((span, _)) if artifact_paths.is_none() => {
+                return Err(syn::Error::new(
+                    span,
+                    "`embed_bytecode` can only be used together with `project`",
+                ))
+            }
+            Some((_, embed_bytecode)) => embed_bytecode,
+            None => false,
+        };
+        let (span, abi) = abi.expect("checked above");
+
+        Ok((
+            span,
+            ContractArgs {
+                name,
+                abi,
+                type_paths,
+                artifact_paths,
+                embed_bytecode,
+                abi_trait,
+            },
+        ))
+    }
+}
 
-        Ok((span, ContractArgs { name, abi }))
+/// Resolves the given path relative to the manifest directory of the crate
+/// being compiled. Absolute paths are returned unchanged.
+fn manifest_relative(path: &str) -> PathBuf {
+    match env::var("CARGO_MANIFEST_DIR") {
+        Ok(manifest_dir) => Path::new(&manifest_dir).join(path),
+        Err(_) => PathBuf::from(path),
+    }
+}
+
+/// Paths to the artifacts `forc build` places in the `out/<profile>` folder
+/// of a project.
+struct ForcProjectPaths {
+    abi_path: String,
+    bin_path: String,
+    storage_path: String,
+}
+
+impl ForcProjectPaths {
+    fn new(project_dir: &Path, profile: &str) -> Result<Self, String> {
+        let abs_forc_dir = project_dir.canonicalize().map_err(|_| {
+            format!(
+                "Unable to canonicalize forc project path: {}. Make sure the path is valid!",
+                project_dir.display()
+            )
+        })?;
+
+        let forc_project_name = abs_forc_dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| {
+                format!(
+                    "failed to get project name from path: {}",
+                    abs_forc_dir.display()
+                )
+            })?;
+
+        let compiled_file_path = |suffix: &str| {
+            abs_forc_dir
+                .join("out")
+                .join(profile)
+                .join([forc_project_name, suffix].concat())
+                .to_string_lossy()
+                .to_string()
+        };
+
+        Ok(Self {
+            abi_path: compiled_file_path("-abi.json"),
+            bin_path: compiled_file_path(".bin"),
+            storage_path: compiled_file_path("-storage_slots.json"),
+        })
+    }
+}
+
+/// A single `struct Path => rust::Path` (or `enum ...`) entry mapping an ABI
+/// type onto an existing Rust type.
+struct TypePath {
+    type_field: String,
+    rust_path: String,
+}
+
+impl Parse for TypePath {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let kind = if input.peek(Token![struct]) {
+            input.parse::<Token![struct]>()?;
+            "struct"
+        } else {
+            input.parse::<Token![enum]>()?;
+            "enum"
+        };
+        let abi_path = input.parse::<syn::Path>()?;
+        input.parse::<Token![=>]>()?;
+        let rust_path = input.parse::<syn::Path>()?;
+
+        let abi_name = abi_path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>()
+            .join("::");
+
+        Ok(TypePath {
+            type_field: format!("{kind} {abi_name}"),
+            rust_path: quote! { #rust_path }.to_string(),
+        })
     }
 }
 
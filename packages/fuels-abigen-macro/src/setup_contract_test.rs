@@ -0,0 +1,472 @@
+//! The command based form of `setup_contract_test!`, used to set up several
+//! wallets, contracts, scripts and predicates at once:
+//!
+//! ```ignore
+//! setup_contract_test!(
+//!     Wallets(wallet, other_wallet),
+//!     Abigen(
+//!         Contract(name = FooContract, project = "path/to/foo_contract"),
+//!         Script(name = MyScript, project = "path/to/my_script"),
+//!     ),
+//!     Deploy(name = foo_instance, contract = FooContract, wallet = wallet),
+//!     LoadScript(name = script_instance, script = MyScript, wallet = other_wallet),
+//!     LoadPredicate(name = predicate, project = "path/to/my_predicate"),
+//! );
+//! ```
+//!
+//! Project paths are resolved relative to the manifest directory of the crate
+//! being compiled, like the `project` option of `abigen!`.
+use crate::{manifest_relative, ForcProjectPaths};
+use fuels_core::code_gen::abigen::Abigen;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use rand::prelude::{Rng, SeedableRng, StdRng};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream, Result as ParseResult},
+    punctuated::Punctuated,
+    token, Error, Expr, Ident, LitStr, Token,
+};
+
+/// Returns whether the macro input uses the command based form, i.e. starts
+/// with a command such as `Wallets(...)`.
+pub(crate) fn is_command_input(input: ParseStream) -> bool {
+    input.peek(Ident) && input.peek2(token::Paren)
+}
+
+/// The commands given to `setup_contract_test!`, grouped by kind. Whatever
+/// order they were given in, they are expanded as bindings, wallets,
+/// deployments, scripts and then predicates.
+pub(crate) struct TestSetup {
+    wallets: Option<WalletsCommand>,
+    bindings: Vec<BindingsCommand>,
+    deployments: Vec<DeployCommand>,
+    scripts: Vec<LoadScriptCommand>,
+    predicates: Vec<LoadPredicateCommand>,
+}
+
+struct WalletsCommand {
+    names: Vec<Ident>,
+    config: Option<Expr>,
+}
+
+#[derive(PartialEq, Eq)]
+enum ProgramKind {
+    Contract,
+    Script,
+}
+
+struct BindingsCommand {
+    kind: ProgramKind,
+    name: Ident,
+    project: LitStr,
+}
+
+struct DeployCommand {
+    name: Ident,
+    contract: Ident,
+    wallet: Ident,
+    salt: Option<Expr>,
+}
+
+struct LoadScriptCommand {
+    name: Ident,
+    script: Ident,
+    wallet: Ident,
+}
+
+struct LoadPredicateCommand {
+    name: Ident,
+    project: LitStr,
+}
+
+impl Parse for TestSetup {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let mut setup = TestSetup {
+            wallets: None,
+            bindings: vec![],
+            deployments: vec![],
+            scripts: vec![],
+            predicates: vec![],
+        };
+
+        let commands = Punctuated::<Command, Token![,]>::parse_terminated(input)?;
+        for command in commands {
+            match command {
+                Command::Wallets(span, _) if setup.wallets.is_some() => {
+                    return Err(Error::new(span, "`Wallets` can only be given once"))
+                }
+                Command::Wallets(_, wallets) => setup.wallets = Some(wallets),
+                Command::Abigen(bindings) => setup.bindings.extend(bindings),
+                Command::Deploy(deployment) => setup.deployments.push(deployment),
+                Command::LoadScript(script) => setup.scripts.push(script),
+                Command::LoadPredicate(predicate) => setup.predicates.push(predicate),
+            }
+        }
+
+        setup.validate()?;
+
+        Ok(setup)
+    }
+}
+
+impl TestSetup {
+    /// Checks that instances only refer to programs declared in `Abigen`.
+    fn validate(&self) -> ParseResult<()> {
+        let is_declared = |name: &Ident, kind: ProgramKind| {
+            self.bindings
+                .iter()
+                .any(|bindings| bindings.name == *name && bindings.kind == kind)
+        };
+
+        for deployment in &self.deployments {
+            if !is_declared(&deployment.contract, ProgramKind::Contract) {
+                return Err(Error::new(
+                    deployment.contract.span(),
+                    format!(
+                        "no contract named `{}` is declared in `Abigen`",
+                        deployment.contract
+                    ),
+                ));
+            }
+        }
+
+        for script in &self.scripts {
+            if !is_declared(&script.script, ProgramKind::Script) {
+                return Err(Error::new(
+                    script.script.span(),
+                    format!(
+                        "no script named `{}` is declared in `Abigen`",
+                        script.script
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Generates the bindings, then launches the provider and instantiates
+    /// the wallets, contracts, scripts and predicates.
+    pub(crate) fn expand(&self) -> ParseResult<TokenStream> {
+        let bindings = self
+            .bindings
+            .iter()
+            .map(BindingsCommand::expand)
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        let wallets = self.wallets.as_ref().map(WalletsCommand::expand);
+
+        let deployments = self.deployments.iter().map(DeployCommand::expand);
+
+        let scripts = self.scripts.iter().map(LoadScriptCommand::expand);
+
+        let predicates = self
+            .predicates
+            .iter()
+            .map(LoadPredicateCommand::expand)
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        Ok(quote! {
+            #(#bindings)*
+            #wallets
+            #(#deployments)*
+            #(#scripts)*
+            #(#predicates)*
+        })
+    }
+}
+
+enum Command {
+    Wallets(Span, WalletsCommand),
+    Abigen(Vec<BindingsCommand>),
+    Deploy(DeployCommand),
+    LoadScript(LoadScriptCommand),
+    LoadPredicate(LoadPredicateCommand),
+}
+
+impl Parse for Command {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let command = input.parse::<Ident>()?;
+        let content;
+        parenthesized!(content in input);
+
+        match command.to_string().as_str() {
+            "Wallets" => Ok(Command::Wallets(command.span(), content.parse()?)),
+            "Abigen" => Ok(Command::Abigen(
+                Punctuated::<BindingsCommand, Token![,]>::parse_terminated(&content)?
+                    .into_iter()
+                    .collect(),
+            )),
+            "Deploy" => {
+                let mut args = NamedArgs::parse(&content, &["name", "contract", "wallet", "salt"])?;
+                Ok(Command::Deploy(DeployCommand {
+                    name: args.ident(&command, "name")?,
+                    contract: args.ident(&command, "contract")?,
+                    wallet: args.ident(&command, "wallet")?,
+                    salt: args.take("salt"),
+                }))
+            }
+            "LoadScript" => {
+                let mut args = NamedArgs::parse(&content, &["name", "script", "wallet"])?;
+                Ok(Command::LoadScript(LoadScriptCommand {
+                    name: args.ident(&command, "name")?,
+                    script: args.ident(&command, "script")?,
+                    wallet: args.ident(&command, "wallet")?,
+                }))
+            }
+            "LoadPredicate" => {
+                let mut args = NamedArgs::parse(&content, &["name", "project"])?;
+                Ok(Command::LoadPredicate(LoadPredicateCommand {
+                    name: args.ident(&command, "name")?,
+                    project: args.lit_str(&command, "project")?,
+                }))
+            }
+            other => Err(Error::new(
+                command.span(),
+                format!(
+                    "unknown command `{other}`, expected one of `Wallets`, `Abigen`, `Deploy`, \
+                     `LoadScript` or `LoadPredicate`"
+                ),
+            )),
+        }
+    }
+}
+
+impl Parse for WalletsCommand {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let mut names = vec![];
+        let mut config = None;
+
+        while !input.is_empty() {
+            let name = input.parse::<Ident>()?;
+            if name == "config" && input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                config = Some(input.parse::<Expr>()?);
+            } else {
+                names.push(name);
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        if names.is_empty() {
+            return Err(input.error("`Wallets` expects at least one wallet name"));
+        }
+
+        Ok(WalletsCommand { names, config })
+    }
+}
+
+impl WalletsCommand {
+    fn expand(&self) -> TokenStream {
+        let names = &self.names;
+        let num_wallets = names.len() as u64;
+
+        let config = match &self.config {
+            Some(config) => quote! { #config },
+            None => quote! { WalletsConfig::new(Some(#num_wallets), None, None) },
+        };
+
+        quote! {
+            let mut wallets = launch_custom_provider_and_get_wallets(#config, None, None)
+                .await
+                .into_iter();
+            #(
+                let #names = wallets
+                    .next()
+                    .expect("the `WalletsConfig` creates fewer wallets than `Wallets` names");
+            )*
+        }
+    }
+}
+
+impl Parse for BindingsCommand {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let kind_ident = input.parse::<Ident>()?;
+        let kind = match kind_ident.to_string().as_str() {
+            "Contract" => ProgramKind::Contract,
+            "Script" => ProgramKind::Script,
+            other => {
+                return Err(Error::new(
+                    kind_ident.span(),
+                    format!("unknown program kind `{other}`, expected `Contract` or `Script`"),
+                ))
+            }
+        };
+
+        let content;
+        parenthesized!(content in input);
+        let mut args = NamedArgs::parse(&content, &["name", "project"])?;
+
+        Ok(BindingsCommand {
+            kind,
+            name: args.ident(&kind_ident, "name")?,
+            project: args.lit_str(&kind_ident, "project")?,
+        })
+    }
+}
+
+impl BindingsCommand {
+    fn expand(&self) -> ParseResult<TokenStream> {
+        let paths = ForcProjectPaths::new(&manifest_relative(&self.project.value()), "debug")
+            .map_err(|msg| Error::new(self.project.span(), msg))?;
+
+        let abigen = Abigen::new(&self.name.to_string(), &paths.abi_path)
+            .map(|abigen| abigen.with_artifact_paths(&paths.bin_path, &paths.storage_path));
+        let expanded = match self.kind {
+            ProgramKind::Contract => abigen.and_then(|abigen| abigen.expand_contract()),
+            ProgramKind::Script => abigen.and_then(|abigen| abigen.expand_script()),
+        };
+
+        expanded.map_err(|err| Error::new(self.project.span(), err))
+    }
+}
+
+impl DeployCommand {
+    fn expand(&self) -> TokenStream {
+        let DeployCommand {
+            name,
+            contract,
+            wallet,
+            salt,
+        } = self;
+
+        let salt = match salt {
+            Some(salt) => quote! { #salt },
+            None => {
+                let salt = deterministic_salt(&name.to_string());
+                quote! { [#(#salt),*] }
+            }
+        };
+        let failure_msg = format!("Failed to deploy `{name}`");
+
+        quote! {
+            let #name = #contract::deploy_with_salt(
+                &#wallet,
+                DeployConfig::default(),
+                Salt::from(#salt),
+            )
+            .await
+            .expect(#failure_msg);
+        }
+    }
+}
+
+impl LoadScriptCommand {
+    fn expand(&self) -> TokenStream {
+        let LoadScriptCommand {
+            name,
+            script,
+            wallet,
+        } = self;
+
+        quote! {
+            let #name = #script::new(#wallet.clone(), #script::BIN_PATH);
+        }
+    }
+}
+
+impl LoadPredicateCommand {
+    fn expand(&self) -> ParseResult<TokenStream> {
+        let name = &self.name;
+        let paths = ForcProjectPaths::new(&manifest_relative(&self.project.value()), "debug")
+            .map_err(|msg| Error::new(self.project.span(), msg))?;
+        let bin_path = paths.bin_path;
+        let failure_msg = format!("Failed to load the predicate `{name}`");
+
+        Ok(quote! {
+            let #name = Predicate::load_from(#bin_path).expect(#failure_msg);
+        })
+    }
+}
+
+/// A salt that only depends on the instance name, so that the contract ids
+/// are the same across test runs while every instance gets its own id.
+fn deterministic_salt(instance_name: &str) -> [u8; 32] {
+    let mut seed = [0u8; 32];
+    for (i, byte) in instance_name.bytes().enumerate() {
+        seed[i % 32] ^= byte.rotate_left((i / 32) as u32);
+    }
+
+    StdRng::from_seed(seed).gen()
+}
+
+/// The `key = value` arguments of a command, e.g. `name = foo, wallet = bar`.
+struct NamedArgs {
+    args: Vec<(Ident, Expr)>,
+}
+
+impl NamedArgs {
+    fn parse(input: ParseStream, allowed: &[&str]) -> ParseResult<Self> {
+        let mut args: Vec<(Ident, Expr)> = vec![];
+
+        while !input.is_empty() {
+            let key = input.parse::<Ident>()?;
+            if !allowed.iter().any(|allowed| key == allowed) {
+                return Err(Error::new(
+                    key.span(),
+                    format!(
+                        "unknown argument `{key}`, expected one of: {}",
+                        allowed.join(", ")
+                    ),
+                ));
+            }
+            if args.iter().any(|(other, _)| *other == key) {
+                return Err(Error::new(
+                    key.span(),
+                    format!("`{key}` is given more than once"),
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            args.push((key, input.parse()?));
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(NamedArgs { args })
+    }
+
+    fn take(&mut self, key: &str) -> Option<Expr> {
+        let position = self.args.iter().position(|(name, _)| name == key)?;
+        Some(self.args.remove(position).1)
+    }
+
+    fn required(&mut self, command: &Ident, key: &str) -> ParseResult<Expr> {
+        self.take(key).ok_or_else(|| {
+            Error::new(
+                command.span(),
+                format!("`{command}` is missing the `{key}` argument"),
+            )
+        })
+    }
+
+    fn ident(&mut self, command: &Ident, key: &str) -> ParseResult<Ident> {
+        match self.required(command, key)? {
+            Expr::Path(path) if path.path.get_ident().is_some() => {
+                Ok(path.path.get_ident().cloned().expect("checked above"))
+            }
+            other => Err(Error::new_spanned(
+                other,
+                format!("`{key}` must be an identifier"),
+            )),
+        }
+    }
+
+    fn lit_str(&mut self, command: &Ident, key: &str) -> ParseResult<LitStr> {
+        match self.required(command, key)? {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(literal),
+                ..
+            }) => Ok(literal),
+            other => Err(Error::new_spanned(
+                other,
+                format!("`{key}` must be a string literal"),
+            )),
+        }
+    }
+}
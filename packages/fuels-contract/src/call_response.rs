@@ -1,7 +1,9 @@
 use crate::logs::LogDecoder;
 use fuel_tx::Receipt;
-use fuels_core::{Parameterize, Tokenizable};
-use fuels_types::errors::Error;
+use fuels_core::{Parameterize, Token, Tokenizable};
+use fuels_signers::pending_transaction::PendingTransaction;
+use fuels_types::{bech32::Bech32ContractId, errors::Error};
+use std::time::Duration;
 
 /// [`FuelCallResponse`] is a struct that is returned by a call to the contract or script. Its value
 /// field holds the decoded typed value returned by the contract's method. The other field holds all
@@ -16,6 +18,8 @@ pub struct FuelCallResponse<D> {
     pub receipts: Vec<Receipt>,
     pub gas_used: u64,
     pub log_decoder: LogDecoder,
+    /// The responses of each call of a multi-call, in order. Empty for other calls.
+    pub calls: Vec<ContractCallResponse>,
 }
 // ANCHOR_END: fuel_call_response
 
@@ -36,9 +40,15 @@ impl<D> FuelCallResponse<D> {
             gas_used: Self::get_gas_used(&receipts),
             receipts,
             log_decoder,
+            calls: vec![],
         }
     }
 
+    pub fn with_calls(mut self, calls: Vec<ContractCallResponse>) -> Self {
+        self.calls = calls;
+        self
+    }
+
     pub fn get_logs(&self) -> Result<Vec<String>, Error> {
         self.log_decoder.get_logs(&self.receipts)
     }
@@ -47,3 +57,62 @@ impl<D> FuelCallResponse<D> {
         self.log_decoder.get_logs_with_type::<T>(&self.receipts)
     }
 }
+
+/// The response of one of the calls of a multi-call, with the receipts it produced: from its
+/// `Call` receipt to the `Return` or `ReturnData` receipt ending it.
+#[derive(Debug)]
+pub struct ContractCallResponse {
+    pub contract_id: Bech32ContractId,
+    /// The value returned by the call, see [`decode_value`](Self::decode_value)
+    pub value: Token,
+    pub receipts: Vec<Receipt>,
+    /// The gas used by the call, including the few script instructions preparing the next
+    /// one. `None` if the gas forwarded to the call, or to the next one, was set in its
+    /// `CallParameters`: the remaining gas isn't known then.
+    pub gas_used: Option<u64>,
+    pub log_decoder: LogDecoder,
+}
+
+impl ContractCallResponse {
+    /// Decodes the value returned by the call as `D`.
+    pub fn decode_value<D: Tokenizable>(&self) -> Result<D, Error> {
+        D::from_token(self.value.clone())
+    }
+
+    pub fn get_logs(&self) -> Result<Vec<String>, Error> {
+        self.log_decoder.get_logs(&self.receipts)
+    }
+
+    pub fn get_logs_with_type<T: Tokenizable + Parameterize>(&self) -> Result<Vec<T>, Error> {
+        self.log_decoder.get_logs_with_type::<T>(&self.receipts)
+    }
+}
+
+/// A call submitted to the node with the `submit` method of a call handler, whose transaction
+/// may not be committed yet. Its `response` waits for the commit and decodes the response of the
+/// call, like `call` does.
+#[derive(Debug)]
+pub struct SubmittedCall<H> {
+    pub pending_transaction: PendingTransaction,
+    pub handler: H,
+}
+
+impl<H> SubmittedCall<H> {
+    pub fn new(pending_transaction: PendingTransaction, handler: H) -> Self {
+        Self {
+            pending_transaction,
+            handler,
+        }
+    }
+
+    /// Waits for the transaction to be committed, see [`PendingTransaction::await_commit`], and
+    /// returns its receipts.
+    pub(crate) async fn committed_receipts(
+        &self,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<Receipt>, Error> {
+        self.pending_transaction.await_commit(timeout).await?;
+
+        self.pending_transaction.receipts().await
+    }
+}
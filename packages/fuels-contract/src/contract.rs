@@ -1,8 +1,11 @@
 use crate::{
-    call_response::FuelCallResponse, execution_script::ExecutableFuelCall, logs::LogDecoder,
+    call_response::{ContractCallResponse, FuelCallResponse, SubmittedCall},
+    execution_script::ExecutableFuelCall,
+    logs::LogDecoder,
+    mock::MockContract,
 };
 use fuel_gql_client::{
-    fuel_tx::{Contract as FuelContract, Output, Receipt, StorageSlot, Transaction},
+    fuel_tx::{Contract as FuelContract, Output, Receipt, StorageSlot},
     fuel_types::{Address, AssetId, Salt},
     prelude::PanicReason,
 };
@@ -10,15 +13,16 @@ use fuel_tx::{Checkable, Create};
 use fuels_core::{
     abi_decoder::ABIDecoder,
     abi_encoder::{ABIEncoder, UnresolvedBytes},
-    constants::FAILED_TRANSFER_TO_ADDRESS_SIGNAL,
+    constants::{DEFAULT_GAS_ESTIMATION_TOLERANCE, FAILED_TRANSFER_TO_ADDRESS_SIGNAL},
     parameters::StorageConfiguration,
     parameters::{CallParameters, TxParameters},
-    tx::{Bytes32, ContractId},
+    tx::{field::Inputs, Bytes32, ContractId},
     Parameterize, Selector, Token, Tokenizable,
 };
 use fuels_signers::{
     provider::{Provider, TransactionCost},
-    Signer, WalletUnlocked,
+    tx_builders::CreateTransactionBuilder,
+    WalletUnlocked,
 };
 use fuels_types::{
     bech32::Bech32ContractId,
@@ -30,9 +34,11 @@ use std::{
     fmt::Debug,
     fs,
     marker::PhantomData,
+    ops::RangeInclusive,
     panic,
     path::Path,
     str::FromStr,
+    time::Duration,
 };
 
 /// How many times to attempt to resolve missing tx dependencies.
@@ -101,6 +107,7 @@ impl Contract {
         signature: Selector,
         args: &[Token],
         log_decoder: LogDecoder,
+        mock: Option<MockContract>,
     ) -> Result<ContractCallHandler<D>, Error> {
         let encoded_selector = signature;
 
@@ -125,10 +132,12 @@ impl Contract {
         Ok(ContractCallHandler {
             contract_call,
             tx_parameters,
+            gas_estimation_tolerance: None,
             wallet: wallet.clone(),
             provider: provider.clone(),
             datatype: PhantomData,
             log_decoder,
+            mock,
         })
     }
 
@@ -207,15 +216,22 @@ impl Contract {
         wallet: &WalletUnlocked,
         params: TxParameters,
     ) -> Result<Bech32ContractId, Error> {
-        let (mut tx, contract_id) =
-            Self::contract_deployment_transaction(compiled_contract, params).await?;
+        let (tx_builder, contract_id) =
+            Self::contract_deployment_tx_builder(compiled_contract, params);
 
         // The first witness is the bytecode we're deploying.
         // The signature will be appended at position 1 of
         // the witness list
-        wallet.add_fee_coins(&mut tx, 0, 1).await?;
-        wallet.sign_transaction(&mut tx).await?;
+        let tx = tx_builder.finalize(wallet, 0).await?;
 
+        let result = Self::send_deployment(&tx, wallet).await;
+        wallet.release_resources(tx.inputs()).await;
+        result?;
+
+        Ok(contract_id)
+    }
+
+    async fn send_deployment(tx: &Create, wallet: &WalletUnlocked) -> Result<(), Error> {
         let provider = wallet.get_provider()?;
         let chain_info = provider.chain_info().await?;
 
@@ -223,9 +239,9 @@ impl Contract {
             chain_info.latest_block.header.height.0,
             &chain_info.consensus_parameters.into(),
         )?;
-        provider.send_transaction(&tx).await?;
+        provider.send_transaction(tx).await?;
 
-        Ok(contract_id)
+        Ok(())
     }
 
     pub fn load_contract(
@@ -247,13 +263,8 @@ impl Contract {
         let bin = std::fs::read(binary_filepath)?;
 
         let storage = match storage_path {
-            Some(path) if Path::new(&path).exists() => Self::get_storage_vec(path),
-            Some(path) if !Path::new(&path).exists() => {
-                return Err(Error::InvalidData(path.to_owned()));
-            }
-            _ => {
-                vec![]
-            }
+            Some(path) => Self::storage_slots_from_file(path)?,
+            None => vec![],
         };
 
         Ok(CompiledContract {
@@ -263,6 +274,63 @@ impl Contract {
         })
     }
 
+    /// Creates a compiled contract from its bytecode and the storage slots it
+    /// was compiled with. The storage slots are replaced by the ones found at
+    /// the storage path of `storage_configuration`, if any, and the manual
+    /// storage slots are applied on top of them.
+    pub fn load_contract_from_bytecode(
+        bytecode: Vec<u8>,
+        storage_slots: Vec<StorageSlot>,
+        storage_configuration: &StorageConfiguration,
+        salt: Salt,
+    ) -> Result<CompiledContract, Error> {
+        let storage_slots = match &storage_configuration.storage_path {
+            Some(path) => Self::storage_slots_from_file(path)?,
+            None => storage_slots,
+        };
+
+        let mut compiled_contract = CompiledContract {
+            raw: bytecode,
+            salt,
+            storage_slots,
+        };
+        Self::merge_storage_vectors(storage_configuration, &mut compiled_contract);
+
+        Ok(compiled_contract)
+    }
+
+    /// Reads the storage slots from a JSON file generated by `forc build`.
+    pub fn storage_slots_from_file(storage_path: &str) -> Result<Vec<StorageSlot>, Error> {
+        if !Path::new(storage_path).exists() {
+            return Err(Error::InvalidData(storage_path.to_owned()));
+        }
+
+        Self::storage_slots_from_json(&fs::read_to_string(storage_path)?)
+    }
+
+    /// Parses the storage slots from the contents of a JSON file generated by
+    /// `forc build`.
+    pub fn storage_slots_from_json(storage_json: &str) -> Result<Vec<StorageSlot>, Error> {
+        let storage: Vec<HashMap<String, String>> = serde_json::from_str(storage_json)?;
+
+        storage
+            .iter()
+            .map(|slot| {
+                let parse_field = |field: &str| {
+                    slot.get(field)
+                        .and_then(|value| Bytes32::from_str(value).ok())
+                        .ok_or_else(|| {
+                            Error::InvalidData(format!(
+                                "storage slot has an invalid or missing '{field}': {slot:?}"
+                            ))
+                        })
+                };
+
+                Ok(StorageSlot::new(parse_field("key")?, parse_field("value")?))
+            })
+            .collect()
+    }
+
     fn merge_storage_slots(
         manual_storage: &[StorageSlot],
         contract_storage: &[StorageSlot],
@@ -284,45 +352,28 @@ impl Contract {
         compiled_contract: &CompiledContract,
         params: TxParameters,
     ) -> Result<(Create, Bech32ContractId), Error> {
-        let bytecode_witness_index = 0;
-        let storage_slots: Vec<StorageSlot> = compiled_contract.storage_slots.clone();
-        let witnesses = vec![compiled_contract.raw.clone().into()];
+        let (tx_builder, contract_id) =
+            Self::contract_deployment_tx_builder(compiled_contract, params);
 
-        let (contract_id, state_root) = Self::compute_contract_id_and_state_root(compiled_contract);
+        Ok((tx_builder.build(), contract_id))
+    }
 
-        let outputs = vec![Output::contract_created(contract_id, state_root)];
+    fn contract_deployment_tx_builder(
+        compiled_contract: &CompiledContract,
+        params: TxParameters,
+    ) -> (CreateTransactionBuilder, Bech32ContractId) {
+        let (contract_id, state_root) = Self::compute_contract_id_and_state_root(compiled_contract);
 
-        let tx = Transaction::create(
-            params.gas_price,
-            params.gas_limit,
-            params.maturity,
-            bytecode_witness_index,
+        let tx_builder = CreateTransactionBuilder::prepare_contract_deployment(
+            compiled_contract.raw.clone(),
+            contract_id,
+            state_root,
             compiled_contract.salt,
-            storage_slots,
-            vec![],
-            outputs,
-            witnesses,
+            compiled_contract.storage_slots.clone(),
+            params,
         );
 
-        Ok((tx, contract_id.into()))
-    }
-
-    fn get_storage_vec(storage_path: &str) -> Vec<StorageSlot> {
-        let mut return_storage: Vec<StorageSlot> = vec![];
-
-        let storage_json_string = fs::read_to_string(storage_path).expect("Unable to read file");
-
-        let storage: serde_json::Value = serde_json::from_str(storage_json_string.as_str())
-            .expect("JSON was not well-formatted");
-
-        for slot in storage.as_array().unwrap() {
-            return_storage.push(StorageSlot::new(
-                Bytes32::from_str(slot["key"].as_str().unwrap()).unwrap(),
-                Bytes32::from_str(slot["value"].as_str().unwrap()).unwrap(),
-            ));
-        }
-
-        return_storage
+        (tx_builder, contract_id.into())
     }
 }
 
@@ -414,13 +465,13 @@ impl ContractCall {
         }
     }
 
-    fn is_missing_output_variables(receipts: &[Receipt]) -> bool {
+    pub(crate) fn is_missing_output_variables(receipts: &[Receipt]) -> bool {
         receipts.iter().any(
             |r| matches!(r, Receipt::Revert { ra, .. } if *ra == FAILED_TRANSFER_TO_ADDRESS_SIGNAL),
         )
     }
 
-    fn find_contract_not_in_inputs(receipts: &[Receipt]) -> Option<&Receipt> {
+    pub(crate) fn find_contract_not_in_inputs(receipts: &[Receipt]) -> Option<&Receipt> {
         receipts.iter().find(
             |r| matches!(r, Receipt::Panic { reason, .. } if *reason.reason() == PanicReason::ContractNotInInputs ),
         )
@@ -482,7 +533,7 @@ pub fn get_decoded_output(
 }
 
 // Decode the logged type from the receipt of a `RevertTransactionError` if available
-fn decode_revert_error(err: Error, log_decoder: &LogDecoder) -> Error {
+pub(crate) fn decode_revert_error(err: Error, log_decoder: &LogDecoder) -> Error {
     if let Error::RevertTransactionError(_, receipts) = &err {
         if let Ok(logs) = log_decoder.get_logs(receipts) {
             if let Some(log) = logs.into_iter().next() {
@@ -499,10 +550,15 @@ fn decode_revert_error(err: Error, log_decoder: &LogDecoder) -> Error {
 pub struct ContractCallHandler<D> {
     pub contract_call: ContractCall,
     pub tx_parameters: TxParameters,
+    /// Tolerance applied to the estimated gas limit, if it replaces the one of
+    /// `tx_parameters`. See [`auto_gas_limit`](Self::auto_gas_limit).
+    pub gas_estimation_tolerance: Option<f64>,
     pub wallet: WalletUnlocked,
     pub provider: Provider,
     pub datatype: PhantomData<D>,
     pub log_decoder: LogDecoder,
+    /// Answers the call instead of the node, if set.
+    pub mock: Option<MockContract>,
 }
 
 impl<D> ContractCallHandler<D>
@@ -546,7 +602,7 @@ where
     /// Note that this is a builder method, i.e. use it as a chain:
 
     /// ```ignore
-    /// let params = TxParameters { gas_price: 100, gas_limit: 1000000 };
+    /// let params = TxParameters { gas_price: Some(100), gas_limit: 1000000, ..Default::default() };
     /// my_contract_instance.my_method(...).tx_params(params).call()
     /// ```
     pub fn tx_params(mut self, params: TxParameters) -> Self {
@@ -554,6 +610,20 @@ where
         self
     }
 
+    /// Replaces the gas limit of the transaction parameters with the gas used by the call,
+    /// estimated with a dry-run and increased by `tolerance` (by default
+    /// [`DEFAULT_GAS_ESTIMATION_TOLERANCE`]). The limit is capped at
+    /// [`MAX_GAS_PER_TX`](fuels_core::constants::MAX_GAS_PER_TX).
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// my_contract_instance.my_method(...).auto_gas_limit(None).call()
+    /// ```
+    pub fn auto_gas_limit(mut self, tolerance: Option<f64>) -> Self {
+        self.gas_estimation_tolerance = Some(tolerance.unwrap_or(DEFAULT_GAS_ESTIMATION_TOLERANCE));
+        self
+    }
+
     /// Sets the call parameters for a given contract call.
     /// Note that this is a builder method, i.e. use it as a chain:
     ///
@@ -597,26 +667,52 @@ where
     /// value in its `value` field as an actual typed value `D` (if your method returns `bool`,
     /// it will be a bool, works also for structs thanks to the `abigen!()`).
     /// The other field of [`FuelCallResponse`], `receipts`, contains the receipts of the transaction.
+    /// If the handler holds a [`MockContract`], the call is answered by the mock instead.
     async fn call_or_simulate(&self, simulate: bool) -> Result<FuelCallResponse<D>, Error> {
+        if let Some(mock) = &self.mock {
+            return self.get_response(mock.respond(&self.contract_call)?);
+        }
+
         let script = self.get_executable_call().await?;
 
         let receipts = if simulate {
-            script.simulate(&self.provider).await?
+            script.simulate(&self.provider).await
         } else {
-            script.execute(&self.provider).await?
+            script.execute(&self.provider).await
         };
+        script.release_resources(&self.wallet).await;
 
-        self.get_response(receipts)
+        self.get_response(receipts?)
     }
 
     /// Returns the script that executes the contract call
     pub async fn get_executable_call(&self) -> Result<ExecutableFuelCall, Error> {
-        ExecutableFuelCall::from_contract_calls(
-            std::slice::from_ref(&self.contract_call),
-            &self.tx_parameters,
-            &self.wallet,
-        )
-        .await
+        let tx_parameters = self
+            .provider
+            .apply_gas_price_strategy(self.tx_parameters)
+            .await?;
+
+        let build = |tx_parameters| async move {
+            ExecutableFuelCall::from_contract_calls(
+                std::slice::from_ref(&self.contract_call),
+                &tx_parameters,
+                &self.wallet,
+            )
+            .await
+        };
+
+        match self.gas_estimation_tolerance {
+            Some(tolerance) => {
+                ExecutableFuelCall::with_estimated_gas_limit(
+                    tx_parameters,
+                    tolerance,
+                    &self.wallet,
+                    build,
+                )
+                .await
+            }
+            None => build(tx_parameters).await,
+        }
     }
 
     /// Call a contract's method on the node, in a state-modifying manner.
@@ -626,6 +722,25 @@ where
             .map_err(|err| decode_revert_error(err, &self.log_decoder))
     }
 
+    /// Submits the call to the node without waiting for its transaction to be committed. The
+    /// response of the call is given by the returned [`SubmittedCall`] once it is.
+    /// Fails if the handler holds a [`MockContract`], which has no transaction to submit.
+    pub async fn submit(self) -> Result<SubmittedCall<Self>, Error> {
+        if self.mock.is_some() {
+            return Err(Error::MockError(
+                "a mocked call can't be submitted, use `call` or `simulate` instead".to_string(),
+            ));
+        }
+
+        let script = self.get_executable_call().await?;
+        let pending_transaction = self
+            .wallet
+            .track_submission(script.submit(&self.provider).await, script.tx.inputs())
+            .await?;
+
+        Ok(SubmittedCall::new(pending_transaction, self))
+    }
+
     /// Call a contract's method on the node, in a simulated manner, meaning the state of the
     /// blockchain is *not* modified but simulated.
     /// It is the same as the [`call`] method because the API is more user-friendly this way.
@@ -642,17 +757,24 @@ where
         let script = self.get_executable_call().await?;
         let provider = self.wallet.get_provider()?;
 
-        script.simulate(provider).await?;
+        let result = script.simulate(provider).await;
+        script.release_resources(&self.wallet).await;
+        result?;
 
         Ok(())
     }
 
     /// Simulates the call and attempts to resolve missing tx dependencies.
     /// Forwards the received error if it cannot be fixed.
+    /// Mocked calls have no dependencies to resolve and are returned as is.
     pub async fn estimate_tx_dependencies(
         mut self,
         max_attempts: Option<u64>,
     ) -> Result<Self, Error> {
+        if self.mock.is_some() {
+            return Ok(self);
+        }
+
         let attempts = max_attempts.unwrap_or(DEFAULT_TX_DEP_ESTIMATION_ATTEMPTS);
 
         for _ in 0..attempts {
@@ -696,9 +818,10 @@ where
         let transaction_cost = self
             .provider
             .estimate_transaction_cost(&script.tx, tolerance)
-            .await?;
+            .await;
+        script.release_resources(&self.wallet).await;
 
-        Ok(transaction_cost)
+        transaction_cost
     }
 
     /// Create a [`FuelCallResponse`] from call receipts
@@ -723,6 +846,9 @@ pub struct MultiContractCallHandler {
     pub contract_calls: Vec<ContractCall>,
     pub log_decoder: LogDecoder,
     pub tx_parameters: TxParameters,
+    /// Tolerance applied to the estimated gas limit, if it replaces the one of
+    /// `tx_parameters`. See [`auto_gas_limit`](Self::auto_gas_limit).
+    pub gas_estimation_tolerance: Option<f64>,
     pub wallet: WalletUnlocked,
 }
 
@@ -731,6 +857,7 @@ impl MultiContractCallHandler {
         Self {
             contract_calls: vec![],
             tx_parameters: TxParameters::default(),
+            gas_estimation_tolerance: None,
             wallet,
             log_decoder: LogDecoder {
                 logs_map: HashMap::new(),
@@ -753,18 +880,48 @@ impl MultiContractCallHandler {
         self
     }
 
+    /// Replaces the gas limit of the transaction parameters with the gas used by the calls,
+    /// estimated with a dry-run and increased by `tolerance` (by default
+    /// [`DEFAULT_GAS_ESTIMATION_TOLERANCE`]). The limit is capped at
+    /// [`MAX_GAS_PER_TX`](fuels_core::constants::MAX_GAS_PER_TX).
+    /// Note that this is a builder method
+    pub fn auto_gas_limit(&mut self, tolerance: Option<f64>) -> &mut Self {
+        self.gas_estimation_tolerance = Some(tolerance.unwrap_or(DEFAULT_GAS_ESTIMATION_TOLERANCE));
+        self
+    }
+
     /// Returns the script that executes the contract calls
     pub async fn get_executable_call(&self) -> Result<ExecutableFuelCall, Error> {
         if self.contract_calls.is_empty() {
             panic!("No calls added. Have you used '.add_calls()'?");
         }
 
-        ExecutableFuelCall::from_contract_calls(
-            &self.contract_calls,
-            &self.tx_parameters,
-            &self.wallet,
-        )
-        .await
+        let provider = self.wallet.get_provider()?;
+        let tx_parameters = provider
+            .apply_gas_price_strategy(self.tx_parameters)
+            .await?;
+
+        let build = |tx_parameters| async move {
+            ExecutableFuelCall::from_contract_calls(
+                &self.contract_calls,
+                &tx_parameters,
+                &self.wallet,
+            )
+            .await
+        };
+
+        match self.gas_estimation_tolerance {
+            Some(tolerance) => {
+                ExecutableFuelCall::with_estimated_gas_limit(
+                    tx_parameters,
+                    tolerance,
+                    &self.wallet,
+                    build,
+                )
+                .await
+            }
+            None => build(tx_parameters).await,
+        }
     }
 
     /// Call contract methods on the node, in a state-modifying manner.
@@ -774,6 +931,19 @@ impl MultiContractCallHandler {
             .map_err(|err| decode_revert_error(err, &self.log_decoder))
     }
 
+    /// Submits the calls to the node without waiting for their transaction to be committed. The
+    /// response of the calls is given by the returned [`SubmittedCall`] once it is.
+    pub async fn submit(self) -> Result<SubmittedCall<Self>, Error> {
+        let script = self.get_executable_call().await?;
+        let submission = script.submit(self.wallet.get_provider()?).await;
+        let pending_transaction = self
+            .wallet
+            .track_submission(submission, script.tx.inputs())
+            .await?;
+
+        Ok(SubmittedCall::new(pending_transaction, self))
+    }
+
     /// Call contract methods on the node, in a simulated manner, meaning the state of the
     /// blockchain is *not* modified but simulated.
     /// It is the same as the [`call`] method because the API is more user-friendly this way.
@@ -794,12 +964,13 @@ impl MultiContractCallHandler {
         let provider = self.wallet.get_provider()?;
 
         let receipts = if simulate {
-            script.simulate(provider).await?
+            script.simulate(provider).await
         } else {
-            script.execute(provider).await?
+            script.execute(provider).await
         };
+        script.release_resources(&self.wallet).await;
 
-        self.get_response(receipts)
+        self.get_response(receipts?)
     }
 
     /// Simulates a call without needing to resolve the generic for the return type
@@ -807,7 +978,9 @@ impl MultiContractCallHandler {
         let script = self.get_executable_call().await?;
         let provider = self.wallet.get_provider()?;
 
-        script.simulate(provider).await?;
+        let result = script.simulate(provider).await;
+        script.release_resources(&self.wallet).await;
+        result?;
 
         Ok(())
     }
@@ -864,16 +1037,18 @@ impl MultiContractCallHandler {
             .wallet
             .get_provider()?
             .estimate_transaction_cost(&script.tx, tolerance)
-            .await?;
+            .await;
+        script.release_resources(&self.wallet).await;
 
-        Ok(transaction_cost)
+        transaction_cost
     }
 
-    /// Create a [`FuelCallResponse`] from call receipts
+    /// Create a [`FuelCallResponse`] from call receipts, along with the response of each call
     pub fn get_response<D: Tokenizable + Debug>(
         &self,
         mut receipts: Vec<Receipt>,
     ) -> Result<FuelCallResponse<D>, Error> {
+        let calls = self.get_call_responses(&receipts)?;
         let mut final_tokens = vec![];
 
         for call in self.contract_calls.iter() {
@@ -888,14 +1063,137 @@ impl MultiContractCallHandler {
             D::from_token(tokens_as_tuple)?,
             receipts,
             self.log_decoder.clone(),
-        );
+        )
+        .with_calls(calls);
 
         Ok(response)
     }
+
+    /// Splits the receipts of the calls at their boundaries, from the `Call` receipt of each
+    /// call to the `Return` or `ReturnData` receipt ending it, and decodes the response of each
+    /// call from its own receipts.
+    fn get_call_responses(&self, receipts: &[Receipt]) -> Result<Vec<ContractCallResponse>, Error> {
+        let ranges = call_receipt_ranges(receipts);
+        if ranges.len() != self.contract_calls.len() {
+            return Err(Error::InvalidData(format!(
+                "expected the receipts of {} calls, found {}",
+                self.contract_calls.len(),
+                ranges.len()
+            )));
+        }
+
+        // The gas remaining at the start of each call, and at the end of the script, is known
+        // when the call forwards the remaining gas, see `get_single_call_instructions`
+        let remaining_gas: Vec<Option<u64>> = self
+            .contract_calls
+            .iter()
+            .zip(&ranges)
+            .map(|(call, range)| match &receipts[*range.start()] {
+                Receipt::Call { gas, .. } if call.call_parameters.gas_forwarded.is_none() => {
+                    Some(*gas)
+                }
+                _ => None,
+            })
+            .chain([receipts.iter().rev().find_map(|receipt| match receipt {
+                Receipt::Return { id, val, .. } if *id == ContractId::zeroed() => Some(*val),
+                _ => None,
+            })])
+            .collect();
+
+        self.contract_calls
+            .iter()
+            .zip(ranges)
+            .zip(remaining_gas.windows(2))
+            .map(|((call, range), remaining_gas)| {
+                let call_receipts = receipts[range].to_vec();
+                let value = get_decoded_output(
+                    &mut call_receipts.clone(),
+                    Some(&call.contract_id),
+                    &call.output_param,
+                )?;
+                let gas_used = match remaining_gas {
+                    [Some(start), Some(end)] => start.checked_sub(*end),
+                    _ => None,
+                };
+
+                Ok(ContractCallResponse {
+                    contract_id: call.contract_id.clone(),
+                    value,
+                    receipts: call_receipts,
+                    gas_used,
+                    log_decoder: self.log_decoder.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Ranges of the receipts of each call made by the script, from its `Call` receipt to the
+/// `Return` or `ReturnData` receipt of the same call frame.
+fn call_receipt_ranges(receipts: &[Receipt]) -> Vec<RangeInclusive<usize>> {
+    let mut ranges = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, receipt) in receipts.iter().enumerate() {
+        match receipt {
+            Receipt::Call { .. } => {
+                if depth == 0 {
+                    start = index;
+                }
+                depth += 1;
+            }
+            Receipt::Return { .. } | Receipt::ReturnData { .. } if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    ranges.push(start..=index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    ranges
+}
+
+impl<D> SubmittedCall<ContractCallHandler<D>>
+where
+    D: Tokenizable + Debug,
+{
+    /// Waits for the transaction of the call to be committed, see
+    /// [`PendingTransaction::await_commit`], and returns the response of the call.
+    ///
+    /// [`PendingTransaction::await_commit`]: fuels_signers::pending_transaction::PendingTransaction::await_commit
+    pub async fn response(&self, timeout: Option<Duration>) -> Result<FuelCallResponse<D>, Error> {
+        let receipts = self
+            .committed_receipts(timeout)
+            .await
+            .map_err(|err| decode_revert_error(err, &self.handler.log_decoder))?;
+
+        self.handler.get_response(receipts)
+    }
+}
+
+impl SubmittedCall<MultiContractCallHandler> {
+    /// Waits for the transaction of the calls to be committed, see
+    /// [`PendingTransaction::await_commit`], and returns the response of the calls.
+    ///
+    /// [`PendingTransaction::await_commit`]: fuels_signers::pending_transaction::PendingTransaction::await_commit
+    pub async fn response<D: Tokenizable + Debug>(
+        &self,
+        timeout: Option<Duration>,
+    ) -> Result<FuelCallResponse<D>, Error> {
+        let receipts = self
+            .committed_receipts(timeout)
+            .await
+            .map_err(|err| decode_revert_error(err, &self.handler.log_decoder))?;
+
+        self.handler.get_response(receipts)
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use fuel_tx::ScriptExecutionResult;
     use fuels_test_helpers::launch_provider_and_get_wallet;
 
     use super::*;
@@ -932,4 +1230,49 @@ mod test {
         .await
         .unwrap();
     }
+
+    #[test]
+    fn multi_call_response_is_split_per_call() -> Result<(), Error> {
+        let calls: Vec<ContractCall> = (0..2)
+            .map(|_| ContractCall {
+                output_param: ParamType::U64,
+                ..ContractCall::new_with_random_id()
+            })
+            .collect();
+        let [first_id, second_id]: [ContractId; 2] = [
+            (&calls[0].contract_id).into(),
+            (&calls[1].contract_id).into(),
+        ];
+        let nested_id = ContractId::from([1u8; 32]);
+        let script_id = ContractId::zeroed();
+
+        let mut multi_call_handler =
+            MultiContractCallHandler::new(WalletUnlocked::new_random(None));
+        multi_call_handler.contract_calls = calls;
+
+        let call = |id, to, gas| Receipt::call(id, to, 0, AssetId::default(), gas, 0, 0, 0, 0);
+        let receipts = vec![
+            call(script_id, first_id, 1000),
+            call(first_id, nested_id, 950),
+            Receipt::ret(nested_id, 7, 0, 0),
+            Receipt::ret(first_id, 42, 0, 0),
+            call(script_id, second_id, 900),
+            Receipt::ret(second_id, 5, 0, 0),
+            Receipt::ret(script_id, 850, 0, 0),
+            Receipt::script_result(ScriptExecutionResult::Success, 150),
+        ];
+
+        let response = multi_call_handler.get_response::<(u64, u64)>(receipts)?;
+
+        assert_eq!(response.value, (42, 5));
+        let [first, second] = [&response.calls[0], &response.calls[1]];
+        assert_eq!(first.decode_value::<u64>()?, 42);
+        assert_eq!(first.receipts.len(), 4);
+        assert_eq!(first.gas_used, Some(100));
+        assert_eq!(second.decode_value::<u64>()?, 5);
+        assert_eq!(second.receipts.len(), 2);
+        assert_eq!(second.gas_used, Some(50));
+
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@ use fuel_gql_client::fuel_tx::{
 use fuel_gql_client::fuel_types::{
     bytes::padded_len_usize, AssetId, Bytes32, ContractId, Immediate18, Word,
 };
-use fuel_gql_client::fuel_vm::{consts::REG_ONE, prelude::Opcode};
+use fuel_gql_client::fuel_vm::{consts::REG_GGAS, prelude::Opcode};
 use fuels_core::constants::BASE_ASSET_ID;
 use fuels_types::bech32::Bech32Address;
 use fuels_types::constants::WORD_SIZE;
@@ -23,6 +23,9 @@ pub(crate) struct CallOpcodeParamsOffset {
     pub amount_offset: usize,
     pub gas_forwarded_offset: usize,
     pub call_data_offset: usize,
+    /// Whether the call forwards all the remaining gas, instead of the gas stored at
+    /// `gas_forwarded_offset`
+    pub forwards_remaining_gas: bool,
 }
 
 /// Compute how much of each asset is required based on all `CallParameters` of the `ContractCalls`
@@ -60,7 +63,8 @@ pub(crate) fn get_instructions(
         instructions.extend(get_single_call_instructions(call_offsets));
     }
 
-    instructions.extend(Opcode::RET(REG_ONE).to_bytes());
+    // The remaining gas is returned to tell the gas used by the last call
+    instructions.extend(Opcode::RET(REG_GGAS).to_bytes());
 
     instructions
 }
@@ -68,7 +72,7 @@ pub(crate) fn get_instructions(
 /// Returns script data, consisting of the following items in the given order:
 /// 1. Asset ID to be forwarded ([`AssetId::LEN`])
 /// 2. Amount to be forwarded `(1 * `[`WORD_SIZE`]`)`
-/// 3. Gas to be forwarded `(1 * `[`WORD_SIZE`]`)`
+/// 3. Gas to be forwarded, zero when the remaining gas is forwarded `(1 * `[`WORD_SIZE`]`)`
 /// 4. Contract ID ([`ContractId::LEN`]);
 /// 5. Function selector `(1 * `[`WORD_SIZE`]`)`
 /// 6. Calldata offset (optional) `(1 * `[`WORD_SIZE`]`)`
@@ -76,7 +80,6 @@ pub(crate) fn get_instructions(
 pub(crate) fn build_script_data_from_contract_calls(
     calls: &[ContractCall],
     data_offset: usize,
-    gas_limit: u64,
 ) -> (Vec<u8>, Vec<CallOpcodeParamsOffset>) {
     let mut script_data = vec![];
     let mut param_offsets = vec![];
@@ -90,6 +93,7 @@ pub(crate) fn build_script_data_from_contract_calls(
             amount_offset: segment_offset + AssetId::LEN,
             gas_forwarded_offset: segment_offset + AssetId::LEN + WORD_SIZE,
             call_data_offset: segment_offset + AssetId::LEN + 2 * WORD_SIZE,
+            forwards_remaining_gas: call.call_parameters.gas_forwarded.is_none(),
         };
         param_offsets.push(call_param_offsets);
 
@@ -97,8 +101,9 @@ pub(crate) fn build_script_data_from_contract_calls(
 
         script_data.extend(call.call_parameters.amount.to_be_bytes());
 
-        // If gas_forwarded is not set, use the transaction gas limit
-        let gas_forwarded = call.call_parameters.gas_forwarded.unwrap_or(gas_limit);
+        // If gas_forwarded is not set, the script forwards the remaining gas and never
+        // loads this word; it is only kept so that the segment layout stays fixed
+        let gas_forwarded = call.call_parameters.gas_forwarded.unwrap_or_default();
         script_data.extend(gas_forwarded.to_be_bytes());
 
         script_data.extend(call.contract_id.hash().as_ref());
@@ -142,16 +147,29 @@ pub(crate) fn build_script_data_from_contract_calls(
 ///
 /// Note that these are soft rules as we're picking this addresses simply because they
 /// non-reserved register.
+///
+/// Unless the gas forwarded is set, the remaining gas is forwarded, which the VM caps to the
+/// gas of the context anyway: the `Call` receipt then records the remaining gas, telling the gas
+/// used by each call.
 fn get_single_call_instructions(offsets: &CallOpcodeParamsOffset) -> Vec<u8> {
-    let instructions = vec![
+    // Both variants have the same length, see `get_data_offset`
+    let load_gas_forwarded = if offsets.forwards_remaining_gas {
+        [Opcode::NOOP, Opcode::MOVE(0x11, REG_GGAS)]
+    } else {
+        [
+            Opcode::MOVI(0x11, offsets.gas_forwarded_offset as Immediate18),
+            Opcode::LW(0x11, 0x11, 0),
+        ]
+    };
+
+    let mut instructions = vec![
         Opcode::MOVI(0x10, offsets.call_data_offset as Immediate18),
-        Opcode::MOVI(0x11, offsets.gas_forwarded_offset as Immediate18),
-        Opcode::LW(0x11, 0x11, 0),
         Opcode::MOVI(0x12, offsets.amount_offset as Immediate18),
         Opcode::LW(0x12, 0x12, 0),
         Opcode::MOVI(0x13, offsets.asset_id_offset as Immediate18),
-        Opcode::CALL(0x10, 0x12, 0x13, 0x11),
     ];
+    instructions.extend(load_gas_forwarded);
+    instructions.push(Opcode::CALL(0x10, 0x12, 0x13, 0x11));
 
     #[allow(clippy::iter_cloned_collect)]
     instructions.iter().copied().collect::<Vec<u8>>()
@@ -230,7 +248,7 @@ fn generate_contract_outputs(num_of_contracts: usize) -> Vec<Output> {
         .collect()
 }
 
-fn convert_to_signed_resources(spendable_resources: Vec<Resource>) -> Vec<Input> {
+pub(crate) fn convert_to_signed_resources(spendable_resources: Vec<Resource>) -> Vec<Input> {
     spendable_resources
         .into_iter()
         .map(|resource| match resource {
@@ -271,7 +289,7 @@ pub(crate) fn get_data_offset(num_calls: usize) -> usize {
         + padded_len_usize(len_script)
 }
 
-fn generate_contract_inputs(contract_ids: HashSet<ContractId>) -> Vec<Input> {
+pub(crate) fn generate_contract_inputs(contract_ids: HashSet<ContractId>) -> Vec<Input> {
     contract_ids
         .into_iter()
         .enumerate()
@@ -379,7 +397,7 @@ mod test {
             .collect();
 
         // Act
-        let (script_data, param_offsets) = build_script_data_from_contract_calls(&calls, 0, 0);
+        let (script_data, param_offsets) = build_script_data_from_contract_calls(&calls, 0);
 
         // Assert
         assert_eq!(param_offsets.len(), NUM_CALLS);
@@ -0,0 +1,238 @@
+use crate::logs::LogDecoder;
+use fuel_gql_client::{
+    client::{
+        types::{TransactionResponse, TransactionStatus},
+        PageDirection, PaginationRequest,
+    },
+    fuel_tx::{field::Inputs, Bytes32, ContractId, Input, Transaction},
+};
+use fuels_core::{Parameterize, Tokenizable};
+use fuels_signers::provider::{Provider, ProviderError};
+use fuels_types::{bech32::Bech32ContractId, errors::Error};
+use futures::stream::{self, BoxStream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{collections::VecDeque, slice, time::Duration};
+
+/// Number of blocks fetched at once by a [`ContractEventStream`]
+const BLOCKS_PER_PAGE: usize = 100;
+
+/// Position of a [`ContractEventStream`] in the chain: the events at or after this position
+/// are the ones left to read. Persist it to resume reading after a restart.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct EventCursor {
+    pub block_height: u64,
+    pub tx_index: usize,
+    pub receipt_index: usize,
+}
+
+impl EventCursor {
+    /// Cursor reading the events from the start of the block at `block_height`.
+    pub fn at_height(block_height: u64) -> Self {
+        Self {
+            block_height,
+            ..Self::default()
+        }
+    }
+}
+
+/// A log of a contract, decoded as `T`, along with where it was emitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractEvent<T> {
+    pub event: T,
+    pub block_height: u64,
+    pub tx_id: Bytes32,
+    /// Index of the transaction in its block
+    pub tx_index: usize,
+    /// Index of the `Log` or `LogData` receipt among the receipts of the transaction
+    pub receipt_index: usize,
+}
+
+impl<T> ContractEvent<T> {
+    /// Cursor of the position right after this event.
+    pub fn next_cursor(&self) -> EventCursor {
+        EventCursor {
+            block_height: self.block_height,
+            tx_index: self.tx_index,
+            receipt_index: self.receipt_index + 1,
+        }
+    }
+}
+
+/// Reads the logs of type `T` of a contract, block after block, starting at a cursor.
+///
+/// Only the successful transactions having the contract as an input are considered, their
+/// `Log` and `LogData` receipts are decoded with the `LogDecoder` of the contract.
+#[derive(Debug)]
+pub struct ContractEventStream<T> {
+    provider: Provider,
+    contract_id: ContractId,
+    log_decoder: LogDecoder,
+    cursor: EventCursor,
+    next_block_height: u64,
+    events: VecDeque<ContractEvent<T>>,
+}
+
+impl<T: Tokenizable + Parameterize> ContractEventStream<T> {
+    pub fn new(
+        provider: Provider,
+        contract_id: &Bech32ContractId,
+        log_decoder: LogDecoder,
+        cursor: EventCursor,
+    ) -> Self {
+        Self {
+            provider,
+            contract_id: contract_id.into(),
+            log_decoder,
+            cursor,
+            next_block_height: cursor.block_height,
+            events: VecDeque::new(),
+        }
+    }
+
+    /// Position right after the last event returned by [`next`](Self::next).
+    pub fn cursor(&self) -> EventCursor {
+        self.cursor
+    }
+
+    /// Returns the next event, `None` once the events of the latest block were all read. It
+    /// can be called again later, to read the events of the blocks produced since.
+    pub async fn next(&mut self) -> Result<Option<ContractEvent<T>>, Error> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                self.cursor = event.next_cursor();
+                return Ok(Some(event));
+            }
+
+            if !self.read_next_blocks().await? {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Turns this into a never ending stream of events, which checks for new blocks every
+    /// `polling_interval` once the latest block was read. The stream ends after the first error.
+    pub fn into_stream(
+        self,
+        polling_interval: Duration,
+    ) -> BoxStream<'static, Result<ContractEvent<T>, Error>>
+    where
+        T: Send + Sync + 'static,
+    {
+        stream::unfold(Some(self), move |state| async move {
+            let mut event_stream = state?;
+
+            loop {
+                match event_stream.next().await {
+                    Ok(Some(event)) => return Some((Ok(event), Some(event_stream))),
+                    Ok(None) => tokio::time::sleep(polling_interval).await,
+                    Err(e) => return Some((Err(e), None)),
+                }
+            }
+        })
+        .boxed()
+    }
+
+    /// Reads the events of the next page of blocks, returns `false` if there were no new blocks.
+    async fn read_next_blocks(&mut self) -> Result<bool, Error> {
+        // Block heights are the cursors of the pages of blocks
+        let blocks = self
+            .provider
+            .get_blocks(PaginationRequest {
+                cursor: self
+                    .next_block_height
+                    .checked_sub(1)
+                    .map(|height| height.to_string()),
+                results: BLOCKS_PER_PAGE,
+                direction: PageDirection::Forward,
+            })
+            .await?
+            .results;
+
+        let mut read_blocks = false;
+        for block in blocks {
+            let block_height = block.header().height();
+            if block_height < self.next_block_height {
+                continue;
+            }
+
+            for (tx_index, tx_id) in block.transactions().into_iter().enumerate() {
+                self.read_transaction(block_height, tx_index, tx_id).await?;
+            }
+
+            self.next_block_height = block_height + 1;
+            read_blocks = true;
+        }
+
+        Ok(read_blocks)
+    }
+
+    async fn read_transaction(
+        &mut self,
+        block_height: u64,
+        tx_index: usize,
+        tx_id: Bytes32,
+    ) -> Result<(), Error> {
+        let tx_cursor = EventCursor {
+            block_height,
+            tx_index,
+            receipt_index: usize::MAX,
+        };
+        if tx_cursor < self.cursor || !self.touches_contract(&tx_id).await? {
+            return Ok(());
+        }
+
+        let receipts = self
+            .provider
+            .client
+            .receipts(&tx_id.to_string())
+            .await
+            .map_err(ProviderError::from)?;
+
+        for (receipt_index, receipt) in receipts.iter().enumerate() {
+            let receipt_cursor = EventCursor {
+                block_height,
+                tx_index,
+                receipt_index,
+            };
+            if receipt_cursor < self.cursor {
+                continue;
+            }
+
+            let events = self
+                .log_decoder
+                .get_logs_with_type::<T>(slice::from_ref(receipt))?;
+            self.events
+                .extend(events.into_iter().map(|event| ContractEvent {
+                    event,
+                    block_height,
+                    tx_id,
+                    tx_index,
+                    receipt_index,
+                }));
+        }
+
+        Ok(())
+    }
+
+    /// Whether the transaction succeeded and has the contract as an input.
+    async fn touches_contract(&self, tx_id: &Bytes32) -> Result<bool, Error> {
+        let response = self
+            .provider
+            .client
+            .transaction(&tx_id.to_string())
+            .await
+            .map_err(ProviderError::from)?;
+
+        Ok(match response {
+            Some(TransactionResponse {
+                transaction: Transaction::Script(tx),
+                status: TransactionStatus::Success { .. },
+            }) => tx.inputs().iter().any(|input| {
+                matches!(input, Input::Contract { contract_id, .. } if *contract_id == self.contract_id)
+            }),
+            _ => false,
+        })
+    }
+}
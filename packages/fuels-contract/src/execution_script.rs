@@ -1,13 +1,14 @@
 use anyhow::Result;
-use std::fmt::Debug;
+use std::{cmp::min, fmt::Debug, future::Future};
 
-use fuel_gql_client::fuel_tx::{Receipt, Transaction};
+use fuel_gql_client::fuel_tx::Receipt;
 use fuel_gql_client::fuel_types::AssetId;
 
 use fuel_tx::{Checkable, ScriptExecutionResult};
-use fuels_core::parameters::TxParameters;
-use fuels_signers::provider::Provider;
-use fuels_signers::{Signer, WalletUnlocked};
+use fuels_core::{constants::MAX_GAS_PER_TX, parameters::TxParameters, tx::field::Inputs};
+use fuels_signers::pending_transaction::PendingTransaction;
+use fuels_signers::provider::{Provider, TransactionCost};
+use fuels_signers::{tx_builders::ScriptTransactionBuilder, Wallet, WalletUnlocked};
 
 use fuels_types::errors::Error;
 
@@ -15,8 +16,8 @@ use std::vec;
 
 use crate::contract::ContractCall;
 use crate::contract_calls_utils::{
-    build_script_data_from_contract_calls, calculate_required_asset_amounts, get_data_offset,
-    get_instructions, get_transaction_inputs_outputs,
+    build_script_data_from_contract_calls, calculate_required_asset_amounts,
+    convert_to_signed_resources, get_data_offset, get_instructions, get_transaction_inputs_outputs,
 };
 
 /// [`TransactionExecution`] provides methods to create and call/simulate a transaction that carries
@@ -42,7 +43,7 @@ impl ExecutableFuelCall {
         let data_offset = get_data_offset(calls.len());
 
         let (script_data, call_param_offsets) =
-            build_script_data_from_contract_calls(calls, data_offset, tx_parameters.gas_limit);
+            build_script_data_from_contract_calls(calls, data_offset);
 
         let script = get_instructions(calls, call_param_offsets);
 
@@ -51,36 +52,87 @@ impl ExecutableFuelCall {
 
         // Find the spendable resources required for those calls
         for (asset_id, amount) in &required_asset_amounts {
-            let resources = wallet.get_spendable_resources(*asset_id, *amount).await?;
-            spendable_resources.extend(resources);
+            match wallet.reserve_spendable_resources(*asset_id, *amount).await {
+                Ok(resources) => spendable_resources.extend(resources),
+                Err(e) => {
+                    // The resources reserved for the previous assets won't be spent
+                    let inputs = convert_to_signed_resources(spendable_resources);
+                    wallet.release_resources(&inputs).await;
+                    return Err(e);
+                }
+            }
         }
 
         let (inputs, outputs) =
             get_transaction_inputs_outputs(calls, wallet.address(), spendable_resources);
 
-        let mut tx = Transaction::script(
-            tx_parameters.gas_price,
-            tx_parameters.gas_limit,
-            tx_parameters.maturity,
-            script,
-            script_data,
-            inputs,
-            outputs,
-            vec![],
-        );
-
         let base_asset_amount = required_asset_amounts
             .iter()
-            .find(|(asset_id, _)| *asset_id == AssetId::default());
-        match base_asset_amount {
-            Some((_, base_amount)) => wallet.add_fee_coins(&mut tx, *base_amount, 0).await?,
-            None => wallet.add_fee_coins(&mut tx, 0, 0).await?,
-        }
-        wallet.sign_transaction(&mut tx).await.unwrap();
+            .find(|(asset_id, _)| *asset_id == AssetId::default())
+            .map(|(_, base_amount)| *base_amount)
+            .unwrap_or_default();
+
+        let tx = ScriptTransactionBuilder::new()
+            .set_tx_params(*tx_parameters)
+            .set_script(script)
+            .set_script_data(script_data)
+            .set_inputs(inputs)
+            .set_outputs(outputs)
+            .finalize(wallet, base_asset_amount)
+            .await?;
 
         Ok(ExecutableFuelCall::new(tx))
     }
 
+    /// Builds the transaction with `build` twice: first with the given `tx_parameters`, to
+    /// estimate the gas it uses with a dry-run, then with that estimation, increased by
+    /// `tolerance` and capped at [`MAX_GAS_PER_TX`], as gas limit. The dry-run itself lifts the
+    /// gas limit and zeroes the gas price, so the wallet never has to cover the fee of the
+    /// maximum gas limit. The resources reserved by `wallet` for the first transaction are
+    /// released.
+    pub async fn with_estimated_gas_limit<F, Fut>(
+        tx_parameters: TxParameters,
+        tolerance: f64,
+        wallet: &Wallet,
+        build: F,
+    ) -> Result<Self, Error>
+    where
+        F: Fn(TxParameters) -> Fut,
+        Fut: Future<Output = Result<Self, Error>>,
+    {
+        let estimation_call = build(tx_parameters).await?;
+
+        let estimation = wallet
+            .get_provider()?
+            .estimate_transaction_cost(&estimation_call.tx, Some(tolerance))
+            .await;
+        estimation_call.release_resources(wallet).await;
+        let TransactionCost { gas_used, .. } = estimation?;
+
+        build(TxParameters {
+            gas_limit: min(gas_used, MAX_GAS_PER_TX),
+            ..tx_parameters
+        })
+        .await
+    }
+
+    /// Releases the resources spent by the transaction, if `wallet` tracks its resources.
+    pub async fn release_resources(&self, wallet: &Wallet) {
+        wallet.release_resources(self.tx.inputs()).await
+    }
+
+    /// Submit the transaction without waiting for it to be committed.
+    pub async fn submit(&self, provider: &Provider) -> Result<PendingTransaction, Error> {
+        let chain_info = provider.chain_info().await?;
+
+        self.tx.check_without_signatures(
+            chain_info.latest_block.header.height.0,
+            &chain_info.consensus_parameters.into(),
+        )?;
+
+        provider.submit(&self.tx).await
+    }
+
     /// Execute the transaction in a state-modifying manner.
     pub async fn execute(&self, provider: &Provider) -> Result<Vec<Receipt>, Error> {
         let chain_info = provider.chain_info().await?;
@@ -1,8 +1,10 @@
 pub mod call_response;
 pub mod contract;
 pub mod contract_calls_utils;
+pub mod events;
 pub mod execution_script;
 pub mod logs;
+pub mod mock;
 pub mod predicate;
 pub mod script_calls;
 
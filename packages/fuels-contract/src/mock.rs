@@ -0,0 +1,300 @@
+use crate::{contract::ContractCall, logs::LogDecoder};
+use fuel_gql_client::{
+    client::FuelClient,
+    fuel_tx::{Receipt, ScriptExecutionResult},
+};
+use fuels_core::{
+    abi_encoder::ABIEncoder,
+    tx::{Bytes32, ContractId},
+    Parameterize, Selector, Token, Tokenizable,
+};
+use fuels_signers::{provider::Provider, WalletUnlocked};
+use fuels_types::{
+    bech32::Bech32ContractId,
+    errors::Error,
+    param_types::{ParamType, ReturnLocation},
+};
+use sha2::{Digest, Sha256};
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    slice,
+    sync::{Arc, Mutex},
+};
+
+/// Address of the provider given to the wallets of mocked contract instances.
+/// Nothing listens on it, so any request that is not answered by the mock
+/// fails right away instead of reaching a node.
+const UNREACHABLE_PROVIDER_URL: &str = "http://127.0.0.1:0";
+
+/// Stands in for a deployed contract: calls made through a
+/// [`ContractCallHandler`] holding a `MockContract` are answered with the
+/// outcome of the first matching expectation instead of being sent to a node.
+///
+/// Expectations are usually registered through the `expect_*` methods of the
+/// mock generated by `abigen!`. Clones share the same expectations.
+///
+/// Only `call` and `simulate` are mocked, other operations needing a node
+/// (e.g. estimating the transaction cost) fail.
+///
+/// [`ContractCallHandler`]: crate::contract::ContractCallHandler
+#[derive(Debug, Clone, Default)]
+pub struct MockContract {
+    expectations: Arc<Mutex<Vec<Expectation>>>,
+}
+
+#[derive(Debug)]
+struct Expectation {
+    fn_name: String,
+    selector: Selector,
+    /// `None` if the call matches regardless of its arguments.
+    encoded_args: Option<Vec<u8>>,
+    outcome: MockOutcome,
+    /// `None` if the expectation can be matched any number of times.
+    remaining_calls: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+enum MockOutcome {
+    Return(Vec<Receipt>),
+    Revert(String, Vec<Receipt>),
+}
+
+impl MockContract {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a random wallet to be used by mocked contract instances. Its
+    /// provider is never reached.
+    pub fn wallet(&self) -> WalletUnlocked {
+        let client = FuelClient::new(UNREACHABLE_PROVIDER_URL).expect("the mock URL is valid");
+
+        WalletUnlocked::new_random(Some(Provider::new(client)))
+    }
+
+    /// Checks that every expectation registered with [`MockCall::times`] was
+    /// matched the expected number of times.
+    pub fn verify(&self) -> Result<(), Error> {
+        let expectations = self.expectations.lock().expect("mock lock poisoned");
+
+        match expectations.iter().find(
+            |expectation| matches!(expectation.remaining_calls, Some(remaining) if remaining > 0),
+        ) {
+            Some(expectation) => Err(Error::MockError(format!(
+                "expected {} more call(s) to `{}`",
+                expectation.remaining_calls.unwrap_or_default(),
+                expectation.fn_name
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    fn register(&self, expectation: Expectation) {
+        self.expectations
+            .lock()
+            .expect("mock lock poisoned")
+            .push(expectation);
+    }
+
+    /// Returns the receipts the node would have produced for `contract_call`,
+    /// according to the first expectation matching it.
+    pub(crate) fn respond(&self, contract_call: &ContractCall) -> Result<Vec<Receipt>, Error> {
+        let mut expectations = self.expectations.lock().expect("mock lock poisoned");
+        let encoded_args = contract_call.encoded_args.resolve(0);
+
+        let expectation = expectations
+            .iter_mut()
+            .filter(|expectation| expectation.selector == contract_call.encoded_selector)
+            .find(|expectation| {
+                let args_match = match &expectation.encoded_args {
+                    Some(args) => *args == encoded_args,
+                    None => true,
+                };
+                args_match && expectation.remaining_calls != Some(0)
+            });
+
+        let expectation = match expectation {
+            Some(expectation) => expectation,
+            None => {
+                let fn_name = expectations
+                    .iter()
+                    .find(|expectation| expectation.selector == contract_call.encoded_selector)
+                    .map(|expectation| format!("`{}`", expectation.fn_name))
+                    .unwrap_or_else(|| {
+                        format!(
+                            "the function with selector 0x{}",
+                            hex::encode(contract_call.encoded_selector)
+                        )
+                    });
+
+                return Err(Error::MockError(format!(
+                    "unexpected call to {fn_name}: no expectation matches it"
+                )));
+            }
+        };
+
+        if let Some(remaining) = expectation.remaining_calls.as_mut() {
+            *remaining -= 1;
+        }
+
+        match expectation.outcome.clone() {
+            MockOutcome::Return(receipts) => Ok(receipts),
+            MockOutcome::Revert(reason, receipts) => {
+                Err(Error::RevertTransactionError(reason, receipts))
+            }
+        }
+    }
+}
+
+/// Builder for an expected call of a contract function returning `D`. The
+/// expectation is registered once its outcome is given through
+/// [`returns`](Self::returns) or [`reverts`](Self::reverts).
+#[derive(Debug)]
+#[must_use = "expectations are only registered by `returns` or `reverts`"]
+pub struct MockCall<D> {
+    mock: MockContract,
+    contract_id: Bech32ContractId,
+    fn_name: String,
+    selector: Selector,
+    encoded_args: Option<Vec<u8>>,
+    logs: Vec<(ParamType, Token)>,
+    times: Option<usize>,
+    log_decoder: LogDecoder,
+    datatype: PhantomData<D>,
+}
+
+impl<D> MockCall<D>
+where
+    D: Tokenizable + Parameterize + Debug,
+{
+    /// Creates an expectation for a call of `fn_name`, identified by
+    /// `selector`, with the given arguments. Used by the mocks generated by
+    /// `abigen!`.
+    pub fn new(
+        mock: MockContract,
+        contract_id: Bech32ContractId,
+        fn_name: &str,
+        selector: Selector,
+        args: &[Token],
+        log_decoder: LogDecoder,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            mock,
+            contract_id,
+            fn_name: fn_name.to_string(),
+            selector,
+            encoded_args: Some(ABIEncoder::encode(args)?.resolve(0)),
+            logs: vec![],
+            times: None,
+            log_decoder,
+            datatype: PhantomData,
+        })
+    }
+
+    /// Matches the call regardless of its arguments.
+    pub fn any_args(mut self) -> Self {
+        self.encoded_args = None;
+        self
+    }
+
+    /// Matches the call exactly `times` times, see [`MockContract::verify`].
+    /// By default an expectation matches any number of calls.
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = Some(times);
+        self
+    }
+
+    /// Makes the call log `log` before returning or reverting. `T` must be
+    /// one of the types logged by the contract.
+    pub fn with_log<T: Tokenizable + Parameterize>(mut self, log: T) -> Self {
+        self.logs.push((T::param_type(), log.into_token()));
+        self
+    }
+
+    /// Registers the expectation, making the call return `value`.
+    pub fn returns(self, value: D) -> Result<(), Error> {
+        let contract_id = ContractId::from(&self.contract_id);
+
+        let mut receipts = self.log_receipts()?;
+        let encoded_value = ABIEncoder::encode(&[value.into_token()])?.resolve(0);
+        receipts.push(match D::param_type().get_return_location() {
+            ReturnLocation::Return => {
+                let mut word = [0u8; 8];
+                word[..encoded_value.len()].copy_from_slice(&encoded_value);
+                Receipt::ret(contract_id, u64::from_be_bytes(word), 0, 0)
+            }
+            ReturnLocation::ReturnData => {
+                Receipt::return_data(contract_id, 0, digest(&encoded_value), encoded_value, 0, 0)
+            }
+        });
+        receipts.push(Receipt::script_result(ScriptExecutionResult::Success, 0));
+
+        self.register(MockOutcome::Return(receipts));
+        Ok(())
+    }
+
+    /// Registers the expectation, making the call revert with `reason`. As
+    /// for a real call, the error is reported with the first log of the
+    /// call, if any, decoded as the revert reason.
+    pub fn reverts(self, reason: u64) -> Result<(), Error> {
+        let contract_id = ContractId::from(&self.contract_id);
+
+        let mut receipts = self.log_receipts()?;
+        receipts.push(Receipt::revert(contract_id, reason, 0, 0));
+        receipts.push(Receipt::script_result(ScriptExecutionResult::Revert, 0));
+
+        self.register(MockOutcome::Revert(format!("Revert({reason})"), receipts));
+        Ok(())
+    }
+
+    fn log_receipts(&self) -> Result<Vec<Receipt>, Error> {
+        let contract_id = ContractId::from(&self.contract_id);
+
+        self.logs
+            .iter()
+            .map(|(param_type, token)| {
+                let log_id = self
+                    .log_decoder
+                    .logs_map
+                    .iter()
+                    .find(|((log_contract_id, _), log_param_type)| {
+                        *log_contract_id == self.contract_id && log_param_type == &param_type
+                    })
+                    .map(|((_, log_id), _)| *log_id)
+                    .ok_or_else(|| {
+                        Error::MockError(format!(
+                            "`{}` cannot log a value of type {param_type:?}: the contract never logs it",
+                            self.fn_name
+                        ))
+                    })?;
+
+                let data = ABIEncoder::encode(slice::from_ref(token))?.resolve(0);
+                Ok(Receipt::log_data(
+                    contract_id,
+                    0,
+                    log_id,
+                    0,
+                    digest(&data),
+                    data,
+                    0,
+                    0,
+                ))
+            })
+            .collect()
+    }
+
+    fn register(self, outcome: MockOutcome) {
+        self.mock.register(Expectation {
+            fn_name: self.fn_name,
+            selector: self.selector,
+            encoded_args: self.encoded_args,
+            outcome,
+            remaining_calls: self.times,
+        });
+    }
+}
+
+fn digest(data: &[u8]) -> Bytes32 {
+    Bytes32::new(Sha256::digest(data).into())
+}
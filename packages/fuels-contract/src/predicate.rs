@@ -3,6 +3,7 @@ use fuels_types::errors::Error;
 
 use fuels_types::bech32::Bech32Address;
 
+#[derive(Debug, Clone)]
 pub struct Predicate {
     address: Bech32Address,
     code: Vec<u8>,
@@ -1,24 +1,103 @@
 use crate::{
-    call_response::FuelCallResponse, contract::get_decoded_output,
-    execution_script::ExecutableFuelCall, logs::LogDecoder,
+    call_response::{FuelCallResponse, SubmittedCall},
+    contract::{
+        decode_revert_error, get_decoded_output, ContractCall, DEFAULT_TX_DEP_ESTIMATION_ATTEMPTS,
+    },
+    contract_calls_utils::generate_contract_inputs,
+    execution_script::ExecutableFuelCall,
+    logs::LogDecoder,
 };
-use fuel_gql_client::fuel_tx::{Output, Receipt, Transaction};
+use fuel_gql_client::fuel_tx::{
+    field::{Inputs, Script as ScriptField},
+    ConsensusParameters, ContractId, Output, Receipt,
+};
+use fuel_gql_client::fuel_types::{bytes::padded_len_usize, Address, AssetId, Bytes32};
 use fuel_tx::Input;
 use fuels_core::{
+    abi_encoder::UnresolvedBytes,
+    constants::DEFAULT_GAS_ESTIMATION_TOLERANCE,
     parameters::{CallParameters, TxParameters},
     Tokenizable,
 };
-use fuels_signers::{provider::Provider, WalletUnlocked};
-use fuels_types::{errors::Error, param_types::ParamType};
-use std::{fmt::Debug, marker::PhantomData};
+use fuels_signers::{
+    provider::{Provider, TransactionCost},
+    tx_builders::ScriptTransactionBuilder,
+    WalletUnlocked,
+};
+use fuels_types::{bech32::Bech32ContractId, errors::Error, param_types::ParamType};
+use itertools::chain;
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// The binary of a script, either given directly or read from a file the
+/// first time it is needed. Clones share the cached binary, so the file is
+/// read at most once.
+#[derive(Debug, Clone)]
+pub struct ScriptBinary {
+    source: BinarySource,
+}
+
+#[derive(Debug, Clone)]
+enum BinarySource {
+    Bytes(Vec<u8>),
+    File {
+        path: String,
+        cached: Arc<Mutex<Option<Vec<u8>>>>,
+    },
+}
+
+impl ScriptBinary {
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self {
+            source: BinarySource::Bytes(bytes),
+        }
+    }
+
+    pub fn from_file(file_path: &str) -> Self {
+        Self {
+            source: BinarySource::File {
+                path: file_path.to_string(),
+                cached: Default::default(),
+            },
+        }
+    }
+
+    /// Returns the binary, reading it from its file if it wasn't already.
+    pub fn bytes(&self) -> Result<Vec<u8>, Error> {
+        match &self.source {
+            BinarySource::Bytes(bytes) => Ok(bytes.clone()),
+            BinarySource::File { path, cached } => {
+                let mut cached = cached.lock().expect("script binary lock poisoned");
+                if let Some(bytes) = cached.as_ref() {
+                    return Ok(bytes.clone());
+                }
+
+                let bytes = std::fs::read(path).map_err(|err| {
+                    Error::InvalidData(format!(
+                        "failed to read the script binary from `{path}`: {err}"
+                    ))
+                })?;
+                *cached = Some(bytes.clone());
+                Ok(bytes)
+            }
+        }
+    }
+}
 
 #[derive(Debug)]
 /// Contains all data relevant to a single script call
 pub struct ScriptCall {
     pub script_binary: Vec<u8>,
-    pub script_data: Vec<u8>,
+    pub script_data: UnresolvedBytes,
     pub inputs: Vec<Input>,
     pub outputs: Vec<Output>,
+    pub external_contracts: Vec<Bech32ContractId>,
+    pub variable_outputs: Vec<Output>,
     // This field is not currently used but it will be in the future.
     pub call_parameters: CallParameters,
 }
@@ -33,6 +112,66 @@ impl ScriptCall {
         self.inputs = inputs;
         self
     }
+
+    pub fn append_variable_outputs(&mut self, num: u64) {
+        self.variable_outputs.extend(vec![
+            Output::Variable {
+                amount: 0,
+                to: Address::zeroed(),
+                asset_id: AssetId::default(),
+            };
+            num as usize
+        ]);
+    }
+
+    pub fn append_external_contracts(&mut self, contract_id: Bech32ContractId) {
+        self.external_contracts.push(contract_id)
+    }
+
+    /// Returns the inputs and outputs of the transaction running the script:
+    /// the ones set by the user, preceded by an [`Input::Contract`]/[`Output::Contract`]
+    /// pair for each external contract and followed by the variable outputs.
+    ///
+    /// [`Output::Contract`]: fuel_tx::Output::Contract
+    fn transaction_inputs_outputs(&self) -> (Vec<Input>, Vec<Output>) {
+        let contract_ids: HashSet<ContractId> = self
+            .external_contracts
+            .iter()
+            .map(|contract_id| contract_id.into())
+            .collect();
+        let contract_outputs = (0..contract_ids.len())
+            .map(|idx| Output::contract(idx as u8, Bytes32::zeroed(), Bytes32::zeroed()));
+
+        let inputs = chain!(
+            generate_contract_inputs(contract_ids),
+            self.inputs.iter().cloned()
+        )
+        .collect();
+        let outputs = chain!(
+            contract_outputs,
+            self.outputs.iter().cloned(),
+            self.variable_outputs.iter().cloned()
+        )
+        .collect();
+
+        (inputs, outputs)
+    }
+
+    /// Resolves the script data against the address it is loaded at in the
+    /// VM, right after the script binary, so that the pointers to heap types
+    /// (e.g. vectors) point inside of it.
+    pub fn resolved_script_data(&self) -> Vec<u8> {
+        self.script_data
+            .resolve(script_data_offset(&self.script_binary) as u64)
+    }
+}
+
+/// Returns the offset at which the script data of a transaction running
+/// `script_binary` begins
+fn script_data_offset(script_binary: &[u8]) -> usize {
+    ConsensusParameters::DEFAULT.tx_offset()
+        + fuel_tx::Script::script_offset_static()
+        + padded_len_usize(script_binary.len())
 }
 
 #[derive(Debug)]
@@ -41,11 +180,16 @@ impl ScriptCall {
 pub struct ScriptCallHandler<D> {
     pub script_call: ScriptCall,
     pub tx_parameters: TxParameters,
+    /// Tolerance applied to the estimated gas limit, if it replaces the one of
+    /// `tx_parameters`. See [`auto_gas_limit`](Self::auto_gas_limit).
+    pub gas_estimation_tolerance: Option<f64>,
     pub wallet: WalletUnlocked,
     pub provider: Provider,
     pub output_param: ParamType,
     pub datatype: PhantomData<D>,
     pub log_decoder: LogDecoder,
+    /// Wallets signing the transaction besides `wallet`, for the custom inputs they own.
+    pub signers: Vec<WalletUnlocked>,
 }
 
 impl<D> ScriptCallHandler<D>
@@ -54,7 +198,7 @@ where
 {
     pub fn new(
         script_binary: Vec<u8>,
-        script_data: Vec<u8>,
+        script_data: UnresolvedBytes,
         wallet: WalletUnlocked,
         provider: Provider,
         output_param: ParamType,
@@ -65,16 +209,20 @@ where
             script_data,
             inputs: vec![],
             outputs: vec![],
+            external_contracts: vec![],
+            variable_outputs: vec![],
             call_parameters: Default::default(),
         };
         Self {
             script_call,
             tx_parameters: TxParameters::default(),
+            gas_estimation_tolerance: None,
             wallet,
             provider,
             output_param,
             datatype: PhantomData,
             log_decoder,
+            signers: vec![],
         }
     }
 
@@ -82,14 +230,28 @@ where
     /// Note that this is a builder method, i.e. use it as a chain:
     ///
     /// ```ignore
-    /// let params = TxParameters { gas_price: 100, gas_limit: 1000000 };
-    /// instance.main(...).tx_params(params).call()
+    /// let params = TxParameters { gas_price: Some(100), gas_limit: 1000000, ..Default::default() };
+    /// instance.main(...)?.tx_params(params).call()
     /// ```
     pub fn tx_params(mut self, params: TxParameters) -> Self {
         self.tx_parameters = params;
         self
     }
 
+    /// Replaces the gas limit of the transaction parameters with the gas used by the script,
+    /// estimated with a dry-run and increased by `tolerance` (by default
+    /// [`DEFAULT_GAS_ESTIMATION_TOLERANCE`]). The limit is capped at
+    /// [`MAX_GAS_PER_TX`](fuels_core::constants::MAX_GAS_PER_TX).
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// instance.main(...)?.auto_gas_limit(None).call()
+    /// ```
+    pub fn auto_gas_limit(mut self, tolerance: Option<f64>) -> Self {
+        self.gas_estimation_tolerance = Some(tolerance.unwrap_or(DEFAULT_GAS_ESTIMATION_TOLERANCE));
+        self
+    }
+
     pub fn with_outputs(mut self, outputs: Vec<Output>) -> Self {
         self.script_call = self.script_call.with_outputs(outputs);
         self
@@ -100,38 +262,138 @@ where
         self
     }
 
+    /// Sets external contracts as dependencies to this script's call.
+    /// Effectively, this will be used to create [`Input::Contract`]/[`Output::Contract`]
+    /// pairs and set them into the transaction.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// instance.main(...)?.set_contracts(&[contract_id]).call()
+    /// ```
+    ///
+    /// [`Input::Contract`]: fuel_tx::Input::Contract
+    /// [`Output::Contract`]: fuel_tx::Output::Contract
+    pub fn set_contracts(mut self, contract_ids: &[Bech32ContractId]) -> Self {
+        self.script_call.external_contracts = contract_ids.to_vec();
+        self
+    }
+
+    /// Appends an additional external contract as a dependency to this script's call.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// instance.main(...)?.append_contract(additional_contract_id).call()
+    /// ```
+    pub fn append_contract(mut self, contract_id: Bech32ContractId) -> Self {
+        self.script_call.append_external_contracts(contract_id);
+        self
+    }
+
+    /// Appends `num` [`Output::Variable`]s to the transaction.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// instance.main(...)?.append_variable_outputs(num).call()
+    /// ```
+    ///
+    /// [`Output::Variable`]: fuel_tx::Output::Variable
+    pub fn append_variable_outputs(mut self, num: u64) -> Self {
+        self.script_call.append_variable_outputs(num);
+        self
+    }
+
+    /// Adds a wallet signing the transaction, needed when inputs set with
+    /// [`with_inputs`](Self::with_inputs) are owned by it. The handler's own wallet
+    /// signs with the witness index 0, the added signers with the following ones, in
+    /// the order they are added: the inputs of the first one must use the witness index 1.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// let inputs = other_wallet.get_asset_inputs_for_amount(asset_id, amount, 1).await?;
+    /// instance.main(...)?.with_inputs(inputs).add_signer(other_wallet).call()
+    /// ```
+    pub fn add_signer(mut self, signer: WalletUnlocked) -> Self {
+        self.signers.push(signer);
+        self
+    }
+
+    /// Returns the signed transaction that executes the script
+    pub async fn get_executable_call(&self) -> Result<ExecutableFuelCall, Error> {
+        let tx_parameters = self
+            .provider
+            .apply_gas_price_strategy(self.tx_parameters)
+            .await?;
+
+        match self.gas_estimation_tolerance {
+            Some(tolerance) => {
+                ExecutableFuelCall::with_estimated_gas_limit(
+                    tx_parameters,
+                    tolerance,
+                    &self.wallet,
+                    |tx_parameters| self.build_executable_call(tx_parameters),
+                )
+                .await
+            }
+            None => self.build_executable_call(tx_parameters).await,
+        }
+    }
+
+    async fn build_executable_call(
+        &self,
+        tx_parameters: TxParameters,
+    ) -> Result<ExecutableFuelCall, Error> {
+        let (inputs, outputs) = self.script_call.transaction_inputs_outputs();
+
+        let tx = ScriptTransactionBuilder::new()
+            .set_tx_params(tx_parameters)
+            .set_script(self.script_call.script_binary.clone())
+            .set_script_data(self.script_call.resolved_script_data())
+            .set_inputs(inputs)
+            .set_outputs(outputs)
+            .finalize_with_signers(&self.wallet, 0, &self.signers)
+            .await?;
+
+        Ok(ExecutableFuelCall { tx })
+    }
+
     /// Call a script on the node. If `simulate == true`, then the call is done in a
     /// read-only manner, using a `dry-run`. The [`FuelCallResponse`] struct contains the `main`'s value
     /// in its `value` field as an actual typed value `D` (if your method returns `bool`,
     /// it will be a bool, works also for structs thanks to the `abigen!()`).
     /// The other field of [`FuelCallResponse`], `receipts`, contains the receipts of the transaction.
     async fn call_or_simulate(&self, simulate: bool) -> Result<FuelCallResponse<D>, Error> {
-        let mut tx = Transaction::script(
-            self.tx_parameters.gas_price,
-            self.tx_parameters.gas_limit,
-            self.tx_parameters.maturity,
-            self.script_call.script_binary.clone(),
-            self.script_call.script_data.clone(),
-            self.script_call.inputs.clone(), // TODO(iqdecay): allow user to set inputs field
-            self.script_call.outputs.clone(), // TODO(iqdecay): allow user to set outputs field
-            vec![vec![0, 0].into()], //TODO(iqdecay): figure out how to have the right witnesses
-        );
-        self.wallet.add_fee_coins(&mut tx, 0, 0).await?;
-
-        let tx_execution = ExecutableFuelCall { tx };
+        let tx_execution = self.get_executable_call().await?;
 
         let receipts = if simulate {
-            tx_execution.simulate(&self.provider).await?
+            tx_execution.simulate(&self.provider).await
         } else {
-            tx_execution.execute(&self.provider).await?
+            tx_execution.execute(&self.provider).await
         };
+        self.release_resources(&tx_execution).await;
 
-        self.get_response(receipts)
+        self.get_response(receipts?)
     }
 
     /// Call a script on the node, in a state-modifying manner.
     pub async fn call(self) -> Result<FuelCallResponse<D>, Error> {
-        Self::call_or_simulate(&self, false).await
+        Self::call_or_simulate(&self, false)
+            .await
+            .map_err(|err| decode_revert_error(err, &self.log_decoder))
+    }
+
+    /// Submits the script to the node without waiting for its transaction to be committed. The
+    /// response of the script is given by the returned [`SubmittedCall`] once it is.
+    pub async fn submit(self) -> Result<SubmittedCall<Self>, Error> {
+        let tx_execution = self.get_executable_call().await?;
+        let pending_transaction = self
+            .wallet
+            .track_submission(
+                tx_execution.submit(&self.provider).await,
+                tx_execution.tx.inputs(),
+            )
+            .await?;
+
+        Ok(SubmittedCall::new(pending_transaction, self))
     }
 
     /// Call a script on the node, in a simulated manner, meaning the state of the
@@ -140,7 +402,84 @@ where
     ///
     /// [`call`]: Self::call
     pub async fn simulate(self) -> Result<FuelCallResponse<D>, Error> {
-        Self::call_or_simulate(&self, true).await
+        Self::call_or_simulate(&self, true)
+            .await
+            .map_err(|err| decode_revert_error(err, &self.log_decoder))
+    }
+
+    /// Simulates a call without needing to resolve the generic for the return type
+    async fn simulate_without_decode(&self) -> Result<(), Error> {
+        let tx_execution = self.get_executable_call().await?;
+
+        let result = tx_execution.simulate(&self.provider).await;
+        self.release_resources(&tx_execution).await;
+        result?;
+
+        Ok(())
+    }
+
+    /// Simulates the call and attempts to resolve missing tx dependencies.
+    /// Forwards the received error if it cannot be fixed.
+    pub async fn estimate_tx_dependencies(
+        mut self,
+        max_attempts: Option<u64>,
+    ) -> Result<Self, Error> {
+        let attempts = max_attempts.unwrap_or(DEFAULT_TX_DEP_ESTIMATION_ATTEMPTS);
+
+        for _ in 0..attempts {
+            let result = self.simulate_without_decode().await;
+
+            match result {
+                Err(Error::RevertTransactionError(_, receipts))
+                    if ContractCall::is_missing_output_variables(&receipts) =>
+                {
+                    self = self.append_variable_outputs(1);
+                }
+
+                Err(Error::RevertTransactionError(_, ref receipts)) => {
+                    if let Some(receipt) = ContractCall::find_contract_not_in_inputs(receipts) {
+                        let contract_id = Bech32ContractId::from(*receipt.contract_id().unwrap());
+                        self = self.append_contract(contract_id);
+                    } else {
+                        return Err(result.expect_err("Couldn't estimate tx dependencies because we couldn't find the missing contract input"));
+                    }
+                }
+
+                Err(e) => return Err(e),
+                _ => return Ok(self),
+            }
+        }
+
+        // confirm if successful or propagate error
+        match self.call_or_simulate(true).await {
+            Ok(_) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the script's estimated cost
+    pub async fn estimate_transaction_cost(
+        &self,
+        tolerance: Option<f64>,
+    ) -> Result<TransactionCost, Error> {
+        let tx_execution = self.get_executable_call().await?;
+
+        let transaction_cost = self
+            .provider
+            .estimate_transaction_cost(&tx_execution.tx, tolerance)
+            .await;
+        self.release_resources(&tx_execution).await;
+
+        transaction_cost
+    }
+
+    /// Releases the resources spent by the transaction, for the wallet and the signers tracking
+    /// their resources.
+    async fn release_resources(&self, tx_execution: &ExecutableFuelCall) {
+        tx_execution.release_resources(&self.wallet).await;
+        for signer in &self.signers {
+            tx_execution.release_resources(signer).await;
+        }
     }
 
     /// Create a [`FuelCallResponse`] from call receipts
@@ -153,3 +492,55 @@ where
         ))
     }
 }
+
+impl<D> SubmittedCall<ScriptCallHandler<D>>
+where
+    D: Tokenizable + Debug,
+{
+    /// Waits for the transaction of the script to be committed, see
+    /// [`PendingTransaction::await_commit`], and returns the response of the script.
+    ///
+    /// [`PendingTransaction::await_commit`]: fuels_signers::pending_transaction::PendingTransaction::await_commit
+    pub async fn response(&self, timeout: Option<Duration>) -> Result<FuelCallResponse<D>, Error> {
+        let receipts = self
+            .committed_receipts(timeout)
+            .await
+            .map_err(|err| decode_revert_error(err, &self.handler.log_decoder))?;
+
+        self.handler.get_response(receipts)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fuels_core::{abi_encoder::ABIEncoder, Token};
+
+    #[test]
+    fn script_data_pointers_are_resolved_after_the_script_binary() -> Result<(), Error> {
+        let script_binary = vec![0; 10];
+        let vector = Token::Vector(vec![Token::U64(1), Token::U64(2)]);
+        let script_call = ScriptCall {
+            script_binary,
+            script_data: ABIEncoder::encode(&[vector])?,
+            inputs: vec![],
+            outputs: vec![],
+            external_contracts: vec![],
+            variable_outputs: vec![],
+            call_parameters: Default::default(),
+        };
+
+        let script_data = script_call.resolved_script_data();
+
+        let data_offset =
+            ConsensusParameters::DEFAULT.tx_offset() + fuel_tx::Script::script_offset_static() + 16;
+        // the vector's ptr, cap and len are followed by its elements
+        let expected_ptr = (data_offset + 3 * 8) as u64;
+        assert_eq!(script_data[..8], expected_ptr.to_be_bytes());
+        assert_eq!(
+            script_data[24..],
+            [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2]
+        );
+        Ok(())
+    }
+}
@@ -1,19 +1,26 @@
 use super::{
     custom_types::{expand_custom_enum, expand_custom_struct, single_param_type_call},
-    functions_gen::expand_function,
+    functions_gen::{expand_function, expand_mock_function, expand_trait_function},
     resolved_type::resolve_type,
 };
-use crate::code_gen::{bindings::ContractBindings, functions_gen::generate_script_main_function};
+use crate::code_gen::{
+    bindings::ContractBindings,
+    functions_gen::{generate_predicate_encode_function, generate_script_main_function},
+};
 use crate::source::Source;
-use crate::utils::ident;
+use crate::utils::{ident, safe_ident};
 use fuels_types::{
-    bech32::Bech32ContractId, errors::Error, param_types::ParamType, utils::custom_type_name,
-    ABIFunction, ProgramABI, ResolvedLog, TypeDeclaration,
+    bech32::Bech32ContractId,
+    errors::Error,
+    param_types::ParamType,
+    utils::{custom_type_name, custom_type_path},
+    ABIFunction, ProgramABI, ResolvedLog, TypeApplication, TypeDeclaration,
 };
 use inflector::Inflector;
 use proc_macro2::TokenStream;
 use quote::quote;
-use std::collections::HashMap;
+use std::collections::{btree_map::Entry, BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
 
 pub struct Abigen {
     /// Format the code using a locally installed copy of `rustfmt`.
@@ -26,30 +33,164 @@ pub struct Abigen {
     abi: ProgramABI,
 
     types: HashMap<usize, TypeDeclaration>,
+
+    /// User supplied Rust types to be used instead of generating code for the
+    /// ABI type with the matching `type_field` (e.g. `struct Price`).
+    type_paths: BTreeMap<String, TokenStream>,
+
+    /// The ABI file, if the ABI was read from the filesystem. Used to rebuild
+    /// the bindings whenever the file changes.
+    abi_path: Option<PathBuf>,
+
+    /// Paths to the compiled binary and storage slots of a forc project.
+    artifact_paths: Option<ArtifactPaths>,
+
+    /// Embed the compiled binary and storage slots into the bindings instead
+    /// of reading them from `artifact_paths` at runtime.
+    embed_bytecode: bool,
+
+    /// The trait listing the contract methods, if one is to be generated or
+    /// implemented for the contract instance.
+    abi_trait: Option<AbiTrait>,
+}
+
+enum AbiTrait {
+    /// Generate a trait with the given name and implement it.
+    Define(syn::Ident),
+    /// Implement an already existing trait found at the given path.
+    Implement(syn::Path),
+}
+
+/// The kind of program the bindings are generated for.
+#[derive(Clone, Copy)]
+enum ProgramType {
+    Contract,
+    Script,
+    Predicate,
+}
+
+struct ArtifactPaths {
+    bin_path: String,
+    storage_slots_path: String,
 }
 
 impl Abigen {
     /// Creates a new contract with the given ABI JSON source.
     pub fn new<S: AsRef<str>>(contract_name: &str, abi_source: S) -> Result<Self, Error> {
-        let source = Source::parse(abi_source).expect("failed to parse JSON ABI");
+        let read_error = |err: anyhow::Error| {
+            Error::InvalidData(format!("failed to read the JSON ABI: {err:#}"))
+        };
+        let source = Source::parse(abi_source).map_err(read_error)?;
+
+        let json_abi_str = source.get().map_err(read_error)?;
+        let parsed_abi: ProgramABI = serde_json::from_str(&json_abi_str)
+            .map_err(|err| Error::InvalidData(format!("failed to parse the JSON ABI: {err}")))?;
 
-        let json_abi_str = source.get().expect("failed to parse JSON ABI from string");
-        let parsed_abi: ProgramABI = serde_json::from_str(&json_abi_str)?;
+        let abi_path = match source {
+            Source::Local(path) => Some(path),
+            Source::String(_) => None,
+        };
+
+        check_type_ids(&parsed_abi)?;
 
         Ok(Self {
-            types: Abigen::get_types(&parsed_abi),
+            types: resolve_type_names(&parsed_abi)?,
             abi: parsed_abi,
             name: contract_name.to_string(),
             rustfmt: true,
             no_std: false,
+            type_paths: BTreeMap::new(),
+            abi_path,
+            artifact_paths: None,
+            embed_bytecode: false,
+            abi_trait: None,
         })
     }
 
+    /// Exposes the paths to the binary and storage slots files of the
+    /// contract or script as the `BIN_PATH` and `STORAGE_SLOTS_PATH`
+    /// constants of the generated type. Scripts only get `BIN_PATH`.
+    pub fn with_artifact_paths(mut self, bin_path: &str, storage_slots_path: &str) -> Self {
+        self.artifact_paths = Some(ArtifactPaths {
+            bin_path: bin_path.to_string(),
+            storage_slots_path: storage_slots_path.to_string(),
+        });
+        self
+    }
+
     pub fn no_std(mut self) -> Self {
         self.no_std = true;
         self
     }
 
+    /// Maps the ABI type identified by `type_field` (e.g. `struct Price`) to
+    /// an already existing Rust type found at `rust_path` (e.g.
+    /// `my_crate::Price`). No code will be generated for the ABI type, the
+    /// bindings will reference the given type instead. The Rust type must
+    /// implement `Parameterize` and `Tokenizable`.
+    ///
+    /// The path is resolved from inside the generated module so it should
+    /// either start with `crate::` or with the name of an external crate.
+    pub fn with_type_path(mut self, type_field: &str, rust_path: &str) -> Result<Self, Error> {
+        let type_field = type_field.trim();
+
+        let is_custom_type_in_abi = self.abi.types.iter().any(|type_decl| {
+            type_decl.type_field == type_field
+                && (type_decl.is_struct_type() || type_decl.is_enum_type())
+        });
+        if !is_custom_type_in_abi {
+            return Err(Error::InvalidData(format!(
+                "Cannot map `{type_field}` to `{rust_path}`: no such struct or enum in the ABI"
+            )));
+        }
+
+        let path = syn::parse_str::<syn::Path>(rust_path).map_err(|err| {
+            Error::InvalidData(format!("`{rust_path}` is not a valid Rust path: {err}"))
+        })?;
+
+        self.type_paths
+            .insert(type_field.to_string(), quote! { #path });
+        Ok(self)
+    }
+
+    /// Embeds the binary and storage slots given through
+    /// `with_artifact_paths` into the generated code, so that they are not
+    /// read from the filesystem at runtime.
+    pub fn embed_bytecode(mut self) -> Self {
+        self.embed_bytecode = true;
+        self
+    }
+
+    /// Generates a trait named `trait_name` declaring every method of the
+    /// contract, and implements it for the contract instance. Generic code
+    /// written against the trait works with any contract implementing the
+    /// same ABI.
+    pub fn with_abi_trait(mut self, trait_name: &str) -> Result<Self, Error> {
+        let trait_name = syn::parse_str::<syn::Ident>(trait_name).map_err(|err| {
+            Error::InvalidData(format!("`{trait_name}` is not a valid trait name: {err}"))
+        })?;
+
+        self.abi_trait = Some(AbiTrait::Define(trait_name));
+        Ok(self)
+    }
+
+    /// Implements the trait found at `trait_path` for the contract instance,
+    /// instead of generating a new one. The trait is expected to have been
+    /// generated by `with_abi_trait` for a contract sharing the same ABI, with
+    /// the custom types of both bindings mapped onto the same Rust types
+    /// through `with_type_path`.
+    ///
+    /// The path is resolved from inside the generated module so it should
+    /// either start with `crate::` or with the name of an external crate.
+    pub fn implement_abi_trait(mut self, trait_path: &str) -> Result<Self, Error> {
+        let trait_path = syn::parse_str::<syn::Path>(trait_path).map_err(|err| {
+            Error::InvalidData(format!("`{trait_path}` is not a valid Rust path: {err}"))
+        })?;
+
+        self.abi_trait = Some(AbiTrait::Implement(trait_path));
+        Ok(self)
+    }
+
     /// Generates the contract bindings.
     pub fn generate(self) -> Result<ContractBindings, Error> {
         let rustfmt = self.rustfmt;
@@ -70,16 +211,24 @@ impl Abigen {
     pub fn expand_contract(&self) -> Result<TokenStream, Error> {
         let name = ident(&self.name);
         let methods_name = ident(&format!("{}Methods", name));
+        let mock_name = ident(&format!("{}Mock", name));
         let name_mod = ident(&format!("{}_mod", self.name.to_string().to_snake_case()));
 
         let contract_functions = self.contract_functions()?;
+        let mock_functions = self.mock_functions()?;
         let abi_structs = self.abi_structs()?;
         let abi_enums = self.abi_enums()?;
 
-        let resolved_logs = self.resolve_logs();
+        let resolved_logs = self.resolve_logs()?;
         let log_id_param_type_pairs = generate_log_id_param_type_pairs(&resolved_logs);
 
-        let includes = self.includes(false);
+        let includes = self.includes(ProgramType::Contract);
+        let type_path_includes = self.type_path_includes()?;
+        let abi_file_tracking = self.abi_file_tracking();
+
+        let deploy_functions = self.contract_deploy_functions();
+        let abi_trait = self.abi_trait(&name)?;
+        let (configurables_name, configurables_builder) = self.configurables_builder()?;
 
         let code = if self.no_std {
             quote! {}
@@ -88,11 +237,20 @@ impl Abigen {
                 pub struct #name {
                  contract_id: Bech32ContractId,
                  wallet: WalletUnlocked,
+                 mock: Option<MockContract>,
                 }
 
                 impl #name {
+                    #deploy_functions
+
                     pub fn new(contract_id: Bech32ContractId, wallet: WalletUnlocked) -> Self {
-                        Self { contract_id, wallet}
+                        Self { contract_id, wallet, mock: None }
+                    }
+
+                    /// Starts building the values of the configurable constants, to be given
+                    /// to the deploy configuration.
+                    pub fn configurables() -> #configurables_name {
+                        #configurables_name::new()
                     }
 
                     pub fn get_contract_id(&self) -> &Bech32ContractId {
@@ -106,7 +264,7 @@ impl Abigen {
                     pub fn with_wallet(&self, mut wallet: WalletUnlocked) -> Result<Self, SDKError> {
                         let provider = self.wallet.get_provider()?;
                         wallet.set_provider(provider.clone());
-                        Ok(Self { contract_id: self.contract_id.clone(), wallet: wallet})
+                        Ok(Self { contract_id: self.contract_id.clone(), wallet: wallet, mock: self.mock.clone() })
                      }
 
                     pub async fn get_balances(&self) -> Result<HashMap<String, u64>, SDKError> {
@@ -118,6 +276,15 @@ impl Abigen {
                             contract_id: self.contract_id.clone(),
                             wallet: self.wallet.clone(),
                             logs_map: get_logs_hashmap(&[#(#log_id_param_type_pairs),*], &self.contract_id),
+                            mock: self.mock.clone(),
+                        }
+                    }
+
+                    /// Decodes the logs of this contract, e.g. to follow them with a
+                    /// `ContractEventStream`.
+                    pub fn log_decoder(&self) -> LogDecoder {
+                        LogDecoder {
+                            logs_map: get_logs_hashmap(&[#(#log_id_param_type_pairs),*], &self.contract_id),
                         }
                     }
                 }
@@ -127,11 +294,51 @@ impl Abigen {
                     contract_id: Bech32ContractId,
                     wallet: WalletUnlocked,
                     logs_map: HashMap<(Bech32ContractId, u64), ParamType>,
+                    mock: Option<MockContract>,
                 }
 
                 impl #methods_name {
                     #contract_functions
                 }
+
+                /// Programs the answers to the calls made through the instances it creates,
+                /// which never reach a node.
+                #[derive(Debug, Clone)]
+                pub struct #mock_name {
+                    contract_id: Bech32ContractId,
+                    mock: MockContract,
+                    logs_map: HashMap<(Bech32ContractId, u64), ParamType>,
+                }
+
+                impl #mock_name {
+                    pub fn new(contract_id: Bech32ContractId) -> Self {
+                        Self {
+                            logs_map: get_logs_hashmap(&[#(#log_id_param_type_pairs),*], &contract_id),
+                            contract_id,
+                            mock: MockContract::new(),
+                        }
+                    }
+
+                    /// Creates a contract instance whose calls are answered by this mock.
+                    pub fn instance(&self) -> #name {
+                        #name {
+                            contract_id: self.contract_id.clone(),
+                            wallet: self.mock.wallet(),
+                            mock: Some(self.mock.clone()),
+                        }
+                    }
+
+                    /// Checks that the calls expected a fixed number of times were all made.
+                    pub fn verify(&self) -> Result<(), SDKError> {
+                        self.mock.verify()
+                    }
+
+                    #mock_functions
+                }
+
+                #abi_trait
+
+                #configurables_builder
             }
         };
 
@@ -145,6 +352,8 @@ impl Abigen {
                 #![allow(unused_imports)]
 
                 #includes
+                #type_path_includes
+                #abi_file_tracking
 
                 #code
 
@@ -161,26 +370,202 @@ impl Abigen {
         let name = ident(&self.name);
         let name_mod = ident(&format!("{}_mod", self.name.to_string().to_snake_case()));
 
-        let includes = self.includes(true);
+        let includes = self.includes(ProgramType::Script);
+        let type_path_includes = self.type_path_includes()?;
+        let abi_file_tracking = self.abi_file_tracking();
+
+        let resolved_logs = self.resolve_logs()?;
+        let log_id_param_type_pairs = generate_log_id_param_type_pairs(&resolved_logs);
+        let (configurables_name, configurables_builder) = self.configurables_builder()?;
+
+        let load_functions = match &self.artifact_paths {
+            Some(ArtifactPaths { bin_path, .. }) if self.embed_bytecode => quote! {
+                pub const BIN_PATH: &'static str = #bin_path;
+                pub const BYTECODE: &'static [u8] = include_bytes!(#bin_path);
+
+                /// Creates the script from the binary embedded at compile time.
+                pub fn load(wallet: WalletUnlocked) -> Self {
+                    Self::from_binary(wallet, Self::BYTECODE.to_vec())
+                }
+            },
+            Some(ArtifactPaths { bin_path, .. }) => quote! {
+                pub const BIN_PATH: &'static str = #bin_path;
+
+                /// Creates the script from the binary found at `BIN_PATH`.
+                pub fn load(wallet: WalletUnlocked) -> Self {
+                    Self::new(wallet, Self::BIN_PATH)
+                }
+            },
+            None => quote! {},
+        };
 
         let main_script_function = self.script_function()?;
         let code = if self.no_std {
             quote! {}
         } else {
             quote! {
-                #[derive(Debug)]
+                #[derive(Debug, Clone)]
                 pub struct #name{
                     wallet: WalletUnlocked,
-                    binary_filepath: String,
+                    binary: ScriptBinary,
+                    logs_map: HashMap<(Bech32ContractId, u64), ParamType>,
+                    configurables: Configurables,
                 }
 
                 impl #name {
+                    #load_functions
+
+                    /// Creates the script from the binary at `binary_filepath`, read the first
+                    /// time the script is called.
                     pub fn new(wallet: WalletUnlocked, binary_filepath: &str) -> Self {
-                        Self {wallet: wallet, binary_filepath: binary_filepath.to_string()}
+                        Self::with_binary(wallet, ScriptBinary::from_file(binary_filepath))
+                    }
+
+                    pub fn from_binary(wallet: WalletUnlocked, binary: Vec<u8>) -> Self {
+                        Self::with_binary(wallet, ScriptBinary::from_bytes(binary))
+                    }
+
+                    fn with_binary(wallet: WalletUnlocked, binary: ScriptBinary) -> Self {
+                        // The logs of a script are emitted with the null contract id
+                        let script_id = Bech32ContractId::from(ContractId::zeroed());
+                        Self {
+                            wallet,
+                            binary,
+                            logs_map: get_logs_hashmap(&[#(#log_id_param_type_pairs),*], &script_id),
+                            configurables: Configurables::default(),
+                        }
+                    }
+
+                    /// Starts building the values of the configurable constants, see
+                    /// `with_configurables`.
+                    pub fn configurables() -> #configurables_name {
+                        #configurables_name::new()
+                    }
+
+                    /// Sets the values of the configurable constants, written into the binary
+                    /// before running it.
+                    pub fn with_configurables(mut self, configurables: #configurables_name) -> Self {
+                        self.configurables = configurables.into();
+                        self
                     }
 
                     #main_script_function
                 }
+
+                #configurables_builder
+            }
+        };
+
+        let abi_structs = self.abi_structs()?;
+        let abi_enums = self.abi_enums()?;
+        Ok(quote! {
+            pub use #name_mod::*;
+
+            #[allow(clippy::too_many_arguments)]
+            pub mod #name_mod {
+                #![allow(clippy::enum_variant_names)]
+                #![allow(dead_code)]
+
+                #includes
+                #type_path_includes
+                #abi_file_tracking
+
+                #code
+
+                #abi_structs
+                #abi_enums
+
+            }
+        })
+    }
+
+    /// Expand a predicate into Rust bindings based on its ABI: a type holding
+    /// the predicate's code and address, able to encode the arguments of its
+    /// `main` function into predicate data. See `expand_contract` for more
+    /// details.
+    pub fn expand_predicate(&self) -> Result<TokenStream, Error> {
+        let name = ident(&self.name);
+        let name_mod = ident(&format!("{}_mod", self.name.to_string().to_snake_case()));
+
+        let includes = self.includes(ProgramType::Predicate);
+        let type_path_includes = self.type_path_includes()?;
+        let abi_file_tracking = self.abi_file_tracking();
+
+        let load_functions = match &self.artifact_paths {
+            Some(ArtifactPaths { bin_path, .. }) if self.embed_bytecode => quote! {
+                pub const BIN_PATH: &'static str = #bin_path;
+                pub const BYTECODE: &'static [u8] = include_bytes!(#bin_path);
+
+                /// Creates the predicate from the code embedded at compile time.
+                pub fn load() -> Result<Self, SDKError> {
+                    Ok(Self::new(Self::BYTECODE.to_vec()))
+                }
+            },
+            Some(ArtifactPaths { bin_path, .. }) => quote! {
+                pub const BIN_PATH: &'static str = #bin_path;
+
+                /// Creates the predicate from the code found at `BIN_PATH`.
+                pub fn load() -> Result<Self, SDKError> {
+                    Self::load_from(Self::BIN_PATH)
+                }
+            },
+            None => quote! {},
+        };
+
+        let encode_data_function = self.predicate_function()?;
+        let (configurables_name, configurables_builder) = self.configurables_builder()?;
+        let code = if self.no_std {
+            quote! {}
+        } else {
+            quote! {
+                #[derive(Debug, Clone)]
+                pub struct #name {
+                    predicate: Predicate,
+                }
+
+                impl #name {
+                    #load_functions
+
+                    pub fn new(code: Vec<u8>) -> Self {
+                        Self { predicate: Predicate::new(code) }
+                    }
+
+                    pub fn load_from(file_path: &str) -> Result<Self, SDKError> {
+                        Ok(Self { predicate: Predicate::load_from(file_path)? })
+                    }
+
+                    /// The address of the predicate, computed from its code.
+                    pub fn address(&self) -> &Bech32Address {
+                        self.predicate.address()
+                    }
+
+                    pub fn code(&self) -> Vec<u8> {
+                        self.predicate.code()
+                    }
+
+                    pub fn predicate(&self) -> &Predicate {
+                        &self.predicate
+                    }
+
+                    /// Starts building the values of the configurable constants, see
+                    /// `with_configurables`.
+                    pub fn configurables() -> #configurables_name {
+                        #configurables_name::new()
+                    }
+
+                    /// Writes the values of the configurable constants into the code of the
+                    /// predicate, which changes its address.
+                    pub fn with_configurables(self, configurables: #configurables_name) -> Result<Self, SDKError> {
+                        let mut code = self.code();
+                        Configurables::from(configurables).update_constants_in(&mut code)?;
+
+                        Ok(Self::new(code))
+                    }
+
+                    #encode_data_function
+                }
+
+                #configurables_builder
             }
         };
 
@@ -193,8 +578,11 @@ impl Abigen {
             pub mod #name_mod {
                 #![allow(clippy::enum_variant_names)]
                 #![allow(dead_code)]
+                #![allow(unused_imports)]
 
                 #includes
+                #type_path_includes
+                #abi_file_tracking
 
                 #code
 
@@ -206,7 +594,7 @@ impl Abigen {
     }
 
     /// Generates the includes necessary for the abigen.
-    fn includes(&self, is_script: bool) -> TokenStream {
+    fn includes(&self, program_type: ProgramType) -> TokenStream {
         if self.no_std {
             quote! {
                 use alloc::{vec, vec::Vec};
@@ -218,36 +606,49 @@ impl Abigen {
                 use fuels_types::param_types::ParamType;
             }
         } else {
-            let specific_includes = if is_script {
-                quote! {
-                    use fuels::contract::script_calls::{ScriptCallHandler, ScriptCall};
+            let specific_includes = match program_type {
+                ProgramType::Script => quote! {
+                    use fuels::contract::script_calls::{ScriptBinary, ScriptCallHandler, ScriptCall};
                     use fuels::core::abi_encoder::ABIEncoder;
+                    use fuels::core::code_gen::get_logs_hashmap;
                     use fuels::core::parameters::TxParameters;
+                    use fuels::types::bech32::Bech32ContractId;
+                    use std::collections::HashMap;
                     use std::marker::PhantomData;
-                }
-            } else {
-                quote! {
+                },
+                ProgramType::Predicate => quote! {
+                    use fuels::contract::predicate::Predicate;
+                    use fuels::core::abi_encoder::ABIEncoder;
+                    use fuels::types::bech32::Bech32Address;
+                },
+                ProgramType::Contract => quote! {
                     use fuels::contract::contract::{
+                        CompiledContract,
                         Contract,
                         ContractCallHandler,
                         get_decoded_output
                     };
+                    use fuels::contract::mock::{MockCall, MockContract};
                     use fuels::core::abi_decoder::ABIDecoder;
+                    use fuels::core::abi_encoder::ABIEncoder;
                     use fuels::core::code_gen::{
                         function_selector::resolve_fn_selector,
                         get_logs_hashmap,
                     };
                     use fuels::core::{EnumSelector, StringToken, Identity};
+                    use fuels::core::parameters::{DeployConfig, StorageConfiguration};
+                    use fuels::tx::Salt;
                     use fuels::types::{
                         ResolvedLog,
                         bech32::Bech32ContractId
                     };
                     use std::collections::{HashSet, HashMap};
                     use std::str::FromStr;
-                }
+                },
             };
             quote! {
                 use fuels::contract::logs::LogDecoder;
+                use fuels::core::configurables::Configurables;
                 use fuels::core::types::*;
                 use fuels::core::{Tokenizable, Token, Parameterize, try_from_bytes};
                 use fuels::signers::WalletUnlocked;
@@ -260,17 +661,271 @@ impl Abigen {
         }
     }
 
+    /// Generates the artifact path constants of a contract built with forc,
+    /// alongside functions to deploy it or compute its id.
+    fn contract_deploy_functions(&self) -> TokenStream {
+        let ArtifactPaths {
+            bin_path,
+            storage_slots_path,
+        } = match &self.artifact_paths {
+            Some(artifact_paths) => artifact_paths,
+            None => return quote! {},
+        };
+
+        // `forc build` always outputs the storage slots, so a missing file fails the build of the
+        // bindings when embedding them, and the loading of the contract otherwise
+        let (embedded_consts, load_bytecode, load_storage_slots) = if self.embed_bytecode {
+            (
+                quote! {
+                    pub const BYTECODE: &'static [u8] = include_bytes!(#bin_path);
+                    const STORAGE_SLOTS_JSON: &'static str = include_str!(#storage_slots_path);
+                },
+                quote! { Self::BYTECODE.to_vec() },
+                quote! { Contract::storage_slots_from_json(Self::STORAGE_SLOTS_JSON)? },
+            )
+        } else {
+            (
+                quote! {},
+                quote! { std::fs::read(Self::BIN_PATH)? },
+                quote! { Contract::storage_slots_from_file(Self::STORAGE_SLOTS_PATH)? },
+            )
+        };
+
+        quote! {
+            pub const BIN_PATH: &'static str = #bin_path;
+            pub const STORAGE_SLOTS_PATH: &'static str = #storage_slots_path;
+            #embedded_consts
+
+            /// Loads the compiled contract and its storage slots, ready to be deployed with the
+            /// given configuration and salt.
+            pub fn compiled_contract(
+                config: &DeployConfig,
+                salt: Salt,
+            ) -> Result<CompiledContract, SDKError> {
+                let mut bytecode = #load_bytecode;
+                config.configurables.update_constants_in(&mut bytecode)?;
+                let storage_slots = #load_storage_slots;
+
+                Contract::load_contract_from_bytecode(bytecode, storage_slots, &config.storage_configuration, salt)
+            }
+
+            /// Computes the id the contract will have once deployed with the given configuration
+            /// and salt, without connecting to a node.
+            pub fn contract_id(config: &DeployConfig, salt: Salt) -> Result<Bech32ContractId, SDKError> {
+                let compiled_contract = Self::compiled_contract(config, salt)?;
+                let (contract_id, _) = Contract::compute_contract_id_and_state_root(&compiled_contract);
+
+                Ok(contract_id.into())
+            }
+
+            /// Deploys the contract and returns an instance connected to it.
+            pub async fn deploy(wallet: &WalletUnlocked, config: DeployConfig) -> Result<Self, SDKError> {
+                Self::deploy_with_salt(wallet, config, Salt::zeroed()).await
+            }
+
+            /// Deploys the contract with the given salt and returns an instance connected to it.
+            pub async fn deploy_with_salt(
+                wallet: &WalletUnlocked,
+                config: DeployConfig,
+                salt: Salt,
+            ) -> Result<Self, SDKError> {
+                let compiled_contract = Self::compiled_contract(&config, salt)?;
+                let contract_id = Contract::deploy_loaded(&compiled_contract, wallet, config.tx_parameters).await?;
+
+                Ok(Self::new(contract_id, wallet.clone()))
+            }
+        }
+    }
+
+    /// Generates a builder for the values of the program's configurable
+    /// constants, which converts into `Configurables`. Returns its name
+    /// alongside its definition.
+    fn configurables_builder(&self) -> Result<(syn::Ident, TokenStream), Error> {
+        let builder_name = ident(&format!("{}Configurables", self.name));
+
+        let with_functions = self
+            .abi
+            .configurables
+            .iter()
+            .flatten()
+            .map(|configurable| {
+                let fn_name = safe_ident(&format!("with_{}", configurable.name.to_snake_case()));
+                let value_type: TokenStream = resolve_type(&configurable.application, &self.types)
+                    .map_err(|err| {
+                        bindings_error(&format!("configurable `{}`", configurable.name), err)
+                    })?
+                    .into();
+                let offset = configurable.offset;
+
+                Ok(quote! {
+                    pub fn #fn_name(mut self, value: #value_type) -> Result<Self, SDKError> {
+                        let data = ABIEncoder::encode(&[value.into_token()])?.resolve(0);
+                        self.offsets_with_data.push((#offset, data));
+                        Ok(self)
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let builder = quote! {
+            /// Builds the values of the configurable constants.
+            #[derive(Debug, Clone, Default)]
+            pub struct #builder_name {
+                offsets_with_data: Vec<(u64, Vec<u8>)>,
+            }
+
+            impl #builder_name {
+                pub fn new() -> Self {
+                    Self::default()
+                }
+
+                #(#with_functions)*
+            }
+
+            impl From<#builder_name> for Configurables {
+                fn from(builder: #builder_name) -> Self {
+                    Configurables::new(builder.offsets_with_data)
+                }
+            }
+        };
+
+        Ok((builder_name, builder))
+    }
+
+    /// Makes the compiler track the ABI file so that the bindings get
+    /// regenerated whenever it changes.
+    fn abi_file_tracking(&self) -> TokenStream {
+        match &self.abi_path {
+            Some(path) => {
+                let path = path.to_string_lossy().to_string();
+                quote! { const _: &str = include_str!(#path); }
+            }
+            None => quote! {},
+        }
+    }
+
+    /// Brings the user supplied types into the scope of the generated module
+    /// under the name the bindings use for the corresponding ABI type.
+    fn type_path_includes(&self) -> Result<TokenStream, Error> {
+        let includes = self
+            .type_paths
+            .iter()
+            .map(|(type_field, path)| {
+                let type_decl = self
+                    .abi
+                    .types
+                    .iter()
+                    .find(|type_decl| &type_decl.type_field == type_field)
+                    .map(|type_decl| &self.types[&type_decl.type_id])
+                    .expect("mapped types are checked to be in the ABI");
+                let type_ident = ident(&custom_type_name(&type_decl.type_field)?);
+                Ok(quote! { pub use #path as #type_ident; })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(quote! { #(#includes)* })
+    }
+
     pub fn contract_functions(&self) -> Result<TokenStream, Error> {
         let tokenized_functions = self
             .abi
             .functions
             .iter()
-            .map(|function| expand_function(function, &self.types))
+            .map(|function| {
+                expand_function(function, &self.types)
+                    .map_err(|err| bindings_error(&format!("function `{}`", function.name), err))
+            })
+            .collect::<Result<Vec<TokenStream>, Error>>()?;
+        Ok(quote! { #( #tokenized_functions )* })
+    }
+
+    /// Generates the trait listing the contract methods and its
+    /// implementation for the contract instance `name`, or only the latter if
+    /// the trait is defined elsewhere.
+    fn abi_trait(&self, name: &syn::Ident) -> Result<TokenStream, Error> {
+        let abi_trait = match &self.abi_trait {
+            Some(abi_trait) => abi_trait,
+            None => return Ok(quote! {}),
+        };
+
+        let (declarations, implementations): (Vec<_>, Vec<_>) = self
+            .abi
+            .functions
+            .iter()
+            .map(|function| {
+                expand_trait_function(function, &self.types)
+                    .map_err(|err| bindings_error(&format!("function `{}`", function.name), err))
+            })
+            .collect::<Result<Vec<_>, Error>>()?
+            .into_iter()
+            .unzip();
+
+        let implementation = |trait_path: TokenStream| {
+            quote! {
+                impl #trait_path for #name {
+                    fn get_contract_id(&self) -> &Bech32ContractId {
+                        #name::get_contract_id(self)
+                    }
+
+                    fn get_wallet(&self) -> WalletUnlocked {
+                        #name::get_wallet(self)
+                    }
+
+                    #(#implementations)*
+                }
+            }
+        };
+
+        Ok(match abi_trait {
+            AbiTrait::Define(trait_name) => {
+                let implementation = implementation(quote! { #trait_name });
+                quote! {
+                    pub trait #trait_name {
+                        fn get_contract_id(&self) -> &Bech32ContractId;
+
+                        fn get_wallet(&self) -> WalletUnlocked;
+
+                        #(#declarations)*
+                    }
+
+                    #implementation
+                }
+            }
+            AbiTrait::Implement(trait_path) => implementation(quote! { #trait_path }),
+        })
+    }
+
+    pub fn mock_functions(&self) -> Result<TokenStream, Error> {
+        let tokenized_functions = self
+            .abi
+            .functions
+            .iter()
+            .map(|function| {
+                expand_mock_function(function, &self.types)
+                    .map_err(|err| bindings_error(&format!("function `{}`", function.name), err))
+            })
             .collect::<Result<Vec<TokenStream>, Error>>()?;
         Ok(quote! { #( #tokenized_functions )* })
     }
 
     pub fn script_function(&self) -> Result<TokenStream, Error> {
+        let main_function = self.main_function("script")?;
+
+        let tokenized_function = generate_script_main_function(main_function, &self.types)
+            .map_err(|err| bindings_error("function `main`", err))?;
+        Ok(quote! { #tokenized_function })
+    }
+
+    pub fn predicate_function(&self) -> Result<TokenStream, Error> {
+        let main_function = self.main_function("predicate")?;
+
+        let tokenized_function = generate_predicate_encode_function(main_function, &self.types)
+            .map_err(|err| bindings_error("function `main`", err))?;
+        Ok(quote! { #tokenized_function })
+    }
+
+    /// Returns the `main` function of a script or predicate.
+    fn main_function(&self, program_kind: &str) -> Result<&ABIFunction, Error> {
         let functions = self
             .abi
             .functions
@@ -279,12 +934,11 @@ impl Abigen {
             .collect::<Vec<&ABIFunction>>();
 
         if let [main_function] = functions.as_slice() {
-            let tokenized_function = generate_script_main_function(main_function, &self.types)?;
-            Ok(quote! { #tokenized_function })
+            Ok(main_function)
         } else {
-            Err(Error::CompilationError(
-                "The script must have one function named `main` to compile!".to_string(),
-            ))
+            Err(Error::CompilationError(format!(
+                "The {program_kind} must have one function named `main` to compile!"
+            )))
         }
     }
 
@@ -300,13 +954,17 @@ impl Abigen {
                 continue;
             }
 
-            if Abigen::should_skip_codegen(&prop.type_field)? {
+            if Abigen::should_skip_codegen(&prop.type_field)? || self.is_mapped(&prop.type_field) {
                 continue;
             }
 
-            if !seen_struct.contains(&prop.type_field.as_str()) {
-                structs.extend(expand_custom_struct(prop, &self.types)?);
-                seen_struct.push(&prop.type_field);
+            // Use the declaration with the name given by `resolve_type_names`
+            let type_decl = &self.types[&prop.type_id];
+            if !seen_struct.contains(&type_decl.type_field.as_str()) {
+                let tokenized_struct = expand_custom_struct(type_decl, &self.types)
+                    .map_err(|err| bindings_error(&format!("`{}`", prop.type_field), err))?;
+                structs.extend(tokenized_struct);
+                seen_struct.push(&type_decl.type_field);
             }
         }
 
@@ -339,6 +997,11 @@ impl Abigen {
         .any(|e| e == name))
     }
 
+    // Checks whether the user mapped the given type onto an existing Rust type.
+    fn is_mapped(&self, type_field: &str) -> bool {
+        self.type_paths.contains_key(type_field)
+    }
+
     fn abi_enums(&self) -> Result<TokenStream, Error> {
         let mut enums = TokenStream::new();
 
@@ -346,13 +1009,20 @@ impl Abigen {
         let mut seen_enum: Vec<&str> = vec![];
 
         for prop in &self.abi.types {
-            if !prop.is_enum_type() || Abigen::should_skip_codegen(&prop.type_field)? {
+            if !prop.is_enum_type()
+                || Abigen::should_skip_codegen(&prop.type_field)?
+                || self.is_mapped(&prop.type_field)
+            {
                 continue;
             }
 
-            if !seen_enum.contains(&prop.type_field.as_str()) {
-                enums.extend(expand_custom_enum(prop, &self.types)?);
-                seen_enum.push(&prop.type_field);
+            // Use the declaration with the name given by `resolve_type_names`
+            let type_decl = &self.types[&prop.type_id];
+            if !seen_enum.contains(&type_decl.type_field.as_str()) {
+                let tokenized_enum = expand_custom_enum(type_decl, &self.types)
+                    .map_err(|err| bindings_error(&format!("`{}`", prop.type_field), err))?;
+                enums.extend(tokenized_enum);
+                seen_enum.push(&type_decl.type_field);
             }
         }
 
@@ -365,28 +1035,228 @@ impl Abigen {
     }
 
     /// Reads the parsed logged types from the ABI and creates ResolvedLogs
-    fn resolve_logs(&self) -> Vec<ResolvedLog> {
+    fn resolve_logs(&self) -> Result<Vec<ResolvedLog>, Error> {
         self.abi
             .logged_types
             .as_ref()
             .into_iter()
             .flatten()
             .map(|l| {
-                let resolved_type =
-                    resolve_type(&l.application, &self.types).expect("Failed to resolve log type");
+                let resolved_type = resolve_type(&l.application, &self.types)
+                    .map_err(|err| bindings_error(&format!("log id {}", l.log_id), err))?;
                 let param_type_call = single_param_type_call(&resolved_type);
                 let resolved_type_name = TokenStream::from(resolved_type);
 
-                ResolvedLog {
+                Ok(ResolvedLog {
                     log_id: l.log_id,
                     param_type_call,
                     resolved_type_name,
-                }
+                })
             })
             .collect()
     }
 }
 
+/// Checks that every type referenced by the ABI is declared in it, so that a
+/// malformed ABI is reported with the item referencing the missing type
+/// instead of failing while resolving it.
+fn check_type_ids(abi: &ProgramABI) -> Result<(), Error> {
+    let type_ids = abi
+        .types
+        .iter()
+        .map(|type_decl| type_decl.type_id)
+        .collect::<HashSet<_>>();
+
+    fn check_application(
+        item: &str,
+        type_application: &TypeApplication,
+        type_ids: &HashSet<usize>,
+    ) -> Result<(), Error> {
+        if !type_ids.contains(&type_application.type_id) {
+            return Err(Error::InvalidData(format!(
+                "{item} refers to type id {}, which is not declared in the ABI",
+                type_application.type_id
+            )));
+        }
+
+        type_application
+            .type_arguments
+            .iter()
+            .flatten()
+            .try_for_each(|type_argument| check_application(item, type_argument, type_ids))
+    }
+
+    for type_decl in &abi.types {
+        let item = format!("`{}`", type_decl.type_field);
+
+        type_decl
+            .components
+            .iter()
+            .flatten()
+            .try_for_each(|component| check_application(&item, component, &type_ids))?;
+
+        if let Some(id) = type_decl
+            .type_parameters
+            .iter()
+            .flatten()
+            .find(|id| !type_ids.contains(id))
+        {
+            return Err(Error::InvalidData(format!(
+                "{item} refers to type id {id}, which is not declared in the ABI"
+            )));
+        }
+    }
+
+    for function in &abi.functions {
+        let item = format!("function `{}`", function.name);
+
+        function
+            .inputs
+            .iter()
+            .chain(std::iter::once(&function.output))
+            .try_for_each(|type_application| {
+                check_application(&item, type_application, &type_ids)
+            })?;
+    }
+
+    abi.logged_types
+        .iter()
+        .flatten()
+        .try_for_each(|logged_type| {
+            let item = format!("log id {}", logged_type.log_id);
+            check_application(&item, &logged_type.application, &type_ids)
+        })?;
+
+    abi.configurables
+        .iter()
+        .flatten()
+        .try_for_each(|configurable| {
+            let item = format!("configurable `{}`", configurable.name);
+            check_application(&item, &configurable.application, &type_ids)
+        })
+}
+
+/// Names the item of the ABI whose bindings could not be generated in `err`.
+fn bindings_error(item: &str, err: Error) -> Error {
+    Error::CompilationError(format!("failed to generate the bindings of {item}: {err}"))
+}
+
+/// Returns all the types of the ABI with the structs and enums renamed so that
+/// each distinct type gets a distinct, deterministic Rust name.
+///
+/// Types are referred to by their name alone (e.g. `struct Error`) unless
+/// types from different modules share the same name. In that case the module
+/// path is prepended to the name (e.g. `struct lib_a::Error` becomes
+/// `LibAError`). Declarations with the same path are expected to describe the
+/// same type -- an error is returned if their layouts differ since there is
+/// no way to tell them apart.
+fn resolve_type_names(abi: &ProgramABI) -> Result<HashMap<usize, TypeDeclaration>, Error> {
+    let types = Abigen::get_types(abi);
+
+    let custom_types = || {
+        abi.types
+            .iter()
+            .filter(|type_decl| type_decl.is_struct_type() || type_decl.is_enum_type())
+    };
+
+    // name -> (path -> layout)
+    let mut paths_by_name: BTreeMap<String, BTreeMap<Vec<String>, String>> = BTreeMap::new();
+    for type_decl in custom_types() {
+        let path = custom_type_path(&type_decl.type_field)?;
+        let name = custom_type_name(&type_decl.type_field)?;
+        let layout = type_decl_layout(type_decl, &types);
+
+        match paths_by_name.entry(name).or_default().entry(path) {
+            Entry::Vacant(entry) => {
+                entry.insert(layout);
+            }
+            Entry::Occupied(entry) if entry.get() != &layout => {
+                return Err(Error::InvalidData(format!(
+                    "The ABI contains different definitions of `{}`. Cannot generate distinct \
+                     Rust types for them, consider qualifying them with their module path",
+                    type_decl.type_field
+                )));
+            }
+            Entry::Occupied(_) => {}
+        }
+    }
+
+    let mut rust_names: HashMap<Vec<String>, String> = HashMap::new();
+    let mut paths_by_rust_name: HashMap<String, Vec<String>> = HashMap::new();
+    for (name, paths) in paths_by_name {
+        let is_ambiguous = paths.len() > 1;
+        for path in paths.into_keys() {
+            let rust_name = if is_ambiguous {
+                let (name, modules) = path.split_last().expect("path cannot be empty");
+                modules
+                    .iter()
+                    .map(|module| module.to_pascal_case())
+                    .chain(std::iter::once(name.clone()))
+                    .collect()
+            } else {
+                name.clone()
+            };
+
+            if let Some(other_path) = paths_by_rust_name.get(&rust_name) {
+                return Err(Error::InvalidData(format!(
+                    "Both `{}` and `{}` would be generated as `{rust_name}`. Consider mapping one \
+                     of them onto an existing Rust type",
+                    other_path.join("::"),
+                    path.join("::")
+                )));
+            }
+            paths_by_rust_name.insert(rust_name.clone(), path.clone());
+            rust_names.insert(path, rust_name);
+        }
+    }
+
+    types
+        .into_iter()
+        .map(|(type_id, mut type_decl)| {
+            if type_decl.is_struct_type() || type_decl.is_enum_type() {
+                let kind = if type_decl.is_struct_type() {
+                    "struct"
+                } else {
+                    "enum"
+                };
+                let path = custom_type_path(&type_decl.type_field)?;
+                type_decl.type_field = format!("{kind} {}", rust_names[&path]);
+            }
+            Ok((type_id, type_decl))
+        })
+        .collect()
+}
+
+// Describes the layout of the given type declaration (its kind, name and
+// components) so that different declarations of a type can be compared.
+fn type_decl_layout(
+    type_decl: &TypeDeclaration,
+    types: &HashMap<usize, TypeDeclaration>,
+) -> String {
+    let components = type_decl
+        .components
+        .iter()
+        .flatten()
+        .map(|component| format!("{}: {}", component.name, type_app_layout(component, types)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("{} {{{components}}}", type_decl.type_field)
+}
+
+fn type_app_layout(type_app: &TypeApplication, types: &HashMap<usize, TypeDeclaration>) -> String {
+    let type_arguments = type_app
+        .type_arguments
+        .iter()
+        .flatten()
+        .map(|type_arg| type_app_layout(type_arg, types))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let type_decl = &types[&type_app.type_id];
+    format!("{}<{type_arguments}>", type_decl_layout(type_decl, types))
+}
+
 fn generate_log_id_param_type_pairs(resolved_logs: &[ResolvedLog]) -> Vec<TokenStream> {
     resolved_logs
         .iter()
@@ -416,3 +1286,182 @@ pub fn get_logs_hashmap(
 // I (@digorithm) skipped writing these tests for now because all this is indirectly
 // tested at a higher level in the main harness file. So, I incurred a bit of test debt here.
 // Yet, we should test this code directly as well.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom_type(
+        type_id: usize,
+        type_field: &str,
+        components: &[(&str, usize)],
+    ) -> TypeDeclaration {
+        TypeDeclaration {
+            type_id,
+            type_field: type_field.to_string(),
+            components: Some(
+                components
+                    .iter()
+                    .map(|(name, type_id)| TypeApplication {
+                        name: name.to_string(),
+                        type_id: *type_id,
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    fn primitive(type_id: usize, type_field: &str) -> TypeDeclaration {
+        TypeDeclaration {
+            type_id,
+            type_field: type_field.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn resolved_type_fields(types: Vec<TypeDeclaration>) -> Result<Vec<String>, Error> {
+        let abi = ProgramABI {
+            types,
+            ..Default::default()
+        };
+        let mut resolved = resolve_type_names(&abi)?.into_iter().collect::<Vec<_>>();
+        resolved.sort_by_key(|(type_id, _)| *type_id);
+
+        Ok(resolved
+            .into_iter()
+            .map(|(_, type_decl)| type_decl.type_field)
+            .collect())
+    }
+
+    #[test]
+    fn unique_names_are_kept() -> Result<(), Error> {
+        let type_fields = resolved_type_fields(vec![
+            primitive(0, "u64"),
+            custom_type(1, "struct lib_a::Error", &[("code", 0)]),
+            custom_type(2, "enum std::option::Option", &[("None", 0), ("Some", 0)]),
+        ])?;
+
+        assert_eq!(type_fields, ["u64", "struct Error", "enum Option"]);
+        Ok(())
+    }
+
+    #[test]
+    fn same_names_from_different_modules_are_disambiguated() -> Result<(), Error> {
+        let type_fields = resolved_type_fields(vec![
+            primitive(0, "u64"),
+            primitive(1, "bool"),
+            custom_type(2, "struct lib_a::Error", &[("code", 0)]),
+            custom_type(3, "enum other_lib::errors::Error", &[("Fatal", 1)]),
+            custom_type(4, "struct Error", &[("code", 0)]),
+        ])?;
+
+        assert_eq!(
+            type_fields,
+            [
+                "u64",
+                "bool",
+                "struct LibAError",
+                "enum OtherLibErrorsError",
+                "struct Error"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn identical_declarations_are_merged() -> Result<(), Error> {
+        let type_fields = resolved_type_fields(vec![
+            primitive(0, "u64"),
+            custom_type(1, "struct Error", &[("code", 0)]),
+            custom_type(2, "struct Error", &[("code", 0)]),
+        ])?;
+
+        assert_eq!(type_fields, ["u64", "struct Error", "struct Error"]);
+        Ok(())
+    }
+
+    #[test]
+    fn different_declarations_with_the_same_path_are_rejected() {
+        let err = resolved_type_fields(vec![
+            primitive(0, "u64"),
+            primitive(1, "bool"),
+            custom_type(2, "struct Error", &[("code", 0)]),
+            custom_type(3, "struct Error", &[("code", 1)]),
+        ])
+        .expect_err("should have failed since the two `Error`s differ");
+
+        assert!(
+            matches!(&err, Error::InvalidData(msg) if msg.contains("`struct Error`")),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn colliding_disambiguated_names_are_rejected() {
+        let err = resolved_type_fields(vec![
+            primitive(0, "u64"),
+            custom_type(1, "struct lib_a::Error", &[("code", 0)]),
+            custom_type(2, "struct lib::AError", &[("code", 0)]),
+            custom_type(3, "struct LibAError", &[("code", 0)]),
+            custom_type(4, "struct AError", &[("code", 0)]),
+        ])
+        .expect_err("should have failed since two types end up named `LibAError`");
+
+        assert!(
+            matches!(&err, Error::InvalidData(msg) if msg.contains("`LibAError`")),
+            "unexpected error: {err}"
+        );
+    }
+
+    fn abi_with_function(types: Vec<TypeDeclaration>, input_type_id: usize) -> String {
+        let abi = ProgramABI {
+            types,
+            functions: vec![ABIFunction {
+                inputs: vec![TypeApplication {
+                    name: "arg".to_string(),
+                    type_id: input_type_id,
+                    ..Default::default()
+                }],
+                name: "takes_arg".to_string(),
+                output: TypeApplication {
+                    type_id: 0,
+                    ..Default::default()
+                },
+            }],
+            ..Default::default()
+        };
+
+        serde_json::to_string(&abi).expect("the ABI should serialize")
+    }
+
+    #[test]
+    fn undeclared_type_ids_are_rejected() {
+        let abi = abi_with_function(vec![primitive(0, "()")], 1);
+
+        let err = Abigen::new("Contract", abi)
+            .err()
+            .expect("should have failed since type id 1 is not declared");
+
+        assert!(
+            matches!(&err, Error::InvalidData(msg) if msg.contains("function `takes_arg`") && msg.contains("type id 1")),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn expansion_errors_name_the_failing_function() -> Result<(), Error> {
+        let abi = abi_with_function(vec![primitive(0, "()"), primitive(1, "u65")], 1);
+
+        let err = Abigen::new("Contract", abi)?
+            .expand_contract()
+            .expect_err("should have failed since `u65` is not a known type");
+
+        assert!(
+            matches!(&err, Error::CompilationError(msg) if msg.contains("function `takes_arg`")),
+            "unexpected error: {err}"
+        );
+        Ok(())
+    }
+}
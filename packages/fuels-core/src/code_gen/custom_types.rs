@@ -77,6 +77,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_expand_custom_enum_with_only_unit_variants() -> Result<(), Error> {
+        let p = TypeDeclaration {
+            type_id: 0,
+            type_field: String::from("enum Color"),
+            components: Some(vec![
+                TypeApplication {
+                    name: String::from("Red"),
+                    type_id: 1,
+                    ..Default::default()
+                },
+                TypeApplication {
+                    name: String::from("Green"),
+                    type_id: 1,
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        };
+        let types = [
+            (0, p.clone()),
+            (
+                1,
+                TypeDeclaration {
+                    type_id: 1,
+                    type_field: String::from("()"),
+                    ..Default::default()
+                },
+            ),
+        ]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+        let actual = expand_custom_enum(&p, &types)?.to_string();
+        let expected = TokenStream::from_str(
+            r#"
+            # [derive (Clone , Copy , Debug , Eq , PartialEq , Hash)] pub enum Color { Red , Green } impl < > Parameterize for Color < > { fn param_type () -> ParamType { let variants = [("Red" . to_string () , < () > :: param_type ()) , ("Green" . to_string () , < () > :: param_type ())] . to_vec () ; let variants = EnumVariants :: new (variants) . unwrap_or_else (| _ | panic ! ("{} has no variants which isn't allowed!" , "Color")) ; ParamType :: Enum { name : "Color" . to_string () , variants , generics : [] . to_vec () } } } impl < > Tokenizable for Color < > { fn from_token (token : Token) -> Result < Self , SDKError > where Self : Sized , { let gen_err = | msg | { SDKError :: InvalidData (format ! ("Error while instantiating {} from token! {}" , "Color" , msg)) } ; match token { Token :: Enum (selector) => { let (discriminant , variant_token , _) = * selector ; match discriminant { 0u8 => Ok (Self :: Red) , 1u8 => Ok (Self :: Green) , _ => Err (gen_err (format ! ("Discriminant {} doesn't point to any of the enums variants." , discriminant))) , } } _ => Err (gen_err (format ! ("Given token ({}) is not of the type Token::Enum!" , token))) , } } fn into_token (self) -> Token { let (discriminant , token) = match self { Self :: Red => (0u8 , () . into_token ()) , Self :: Green => (1u8 , () . into_token ()) } ; let variants = match Self :: param_type () { ParamType :: Enum { variants , .. } => variants , other => panic ! ("Calling {}::param_type() must return a ParamType::Enum but instead it returned: {:?}" , "Color" , other) } ; Token :: Enum (Box :: new ((discriminant , token , variants))) } } impl < > TryFrom < & [u8] > for Color < > { type Error = SDKError ; fn try_from (bytes : & [u8]) -> Result < Self , Self :: Error > { try_from_bytes (bytes) } } impl < > TryFrom < & Vec < u8 >> for Color < > { type Error = SDKError ; fn try_from (bytes : & Vec < u8 >) -> Result < Self , Self :: Error > { try_from_bytes (& bytes) } } impl < > TryFrom < Vec < u8 >> for Color < > { type Error = SDKError ; fn try_from (bytes : Vec < u8 >) -> Result < Self , Self :: Error > { try_from_bytes (& bytes) } } impl TryFrom < u64 > for Color { type Error = SDKError ; fn try_from (discriminant : u64) -> Result < Self , Self :: Error > { match discriminant { 0u64 => Ok (Self :: Red) , 1u64 => Ok (Self :: Green) , _ => Err (SDKError :: InvalidData (format ! ("Discriminant {} doesn't point to any of the {} variants." , discriminant , "Color"))) , } } } impl From < Color > for u64 { fn from (value : Color) -> Self { match value { Color :: Red => 0u64 , Color :: Green => 1u64 , } } }
+            "#,
+        )?
+        .to_string();
+
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
     #[test]
     fn test_enum_with_no_variants_cannot_be_constructed() -> anyhow::Result<()> {
         let p = TypeDeclaration {
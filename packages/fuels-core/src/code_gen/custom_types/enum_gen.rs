@@ -10,6 +10,10 @@ use std::collections::HashMap;
 /// Returns a TokenStream containing the declaration, `Parameterize`,
 /// `Tokenizable` and `TryFrom` implementations for the enum described by the
 /// given TypeDeclaration.
+///
+/// Enums whose variants all hold `()` are generated as C-like Rust enums
+/// (e.g. `Color::Red` instead of `Color::Red()`) and additionally get
+/// conversions from and into their `u64` discriminant.
 pub fn expand_custom_enum(
     type_decl: &TypeDeclaration,
     types: &HashMap<usize, TypeDeclaration>,
@@ -25,10 +29,20 @@ pub fn expand_custom_enum(
 
     let generics = extract_generic_parameters(type_decl, types)?;
 
-    let enum_def = enum_decl(&enum_ident, &components, &generics);
+    let c_like = generics.is_empty()
+        && components
+            .iter()
+            .all(|component| component.field_type.is_unit());
+
+    let enum_def = enum_decl(&enum_ident, &components, &generics, c_like);
     let parameterize_impl = enum_parameterize_impl(&enum_ident, &components, &generics);
-    let tokenize_impl = enum_tokenizable_impl(&enum_ident, &components, &generics);
+    let tokenize_impl = enum_tokenizable_impl(&enum_ident, &components, &generics, c_like);
     let try_from = impl_try_from(&enum_ident, &generics);
+    let discriminant_conversions = if c_like {
+        enum_discriminant_conversions(&enum_ident, &components)
+    } else {
+        quote! {}
+    };
 
     Ok(quote! {
         #enum_def
@@ -38,6 +52,8 @@ pub fn expand_custom_enum(
         #tokenize_impl
 
         #try_from
+
+        #discriminant_conversions
     })
 }
 
@@ -45,7 +61,19 @@ fn enum_decl(
     enum_ident: &Ident,
     components: &[Component],
     generics: &[TokenStream],
+    c_like: bool,
 ) -> TokenStream {
+    if c_like {
+        let enum_variants = components.iter().map(|component| &component.field_name);
+
+        return quote! {
+            #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+            pub enum #enum_ident {
+                #(#enum_variants),*
+            }
+        };
+    }
+
     let enum_variants = components.iter().map(
         |Component {
              field_name,
@@ -75,6 +103,7 @@ fn enum_tokenizable_impl(
     enum_ident: &Ident,
     components: &[Component],
     generics: &[TokenStream],
+    c_like: bool,
 ) -> TokenStream {
     let enum_ident_stringified = enum_ident.to_string();
 
@@ -86,6 +115,11 @@ fn enum_tokenizable_impl(
                 field_type,
             },
         )| {
+            let u8_discriminant = discriminant as u8;
+            if c_like {
+                return quote! { #u8_discriminant => Ok(Self::#field_name)};
+            }
+
             let value = if field_type.is_unit() {
                 quote! {}
             } else {
@@ -93,7 +127,6 @@ fn enum_tokenizable_impl(
                 quote! { <#field_type>::from_token(variant_token)? }
             };
 
-            let u8_discriminant = discriminant as u8;
             quote! { #u8_discriminant => Ok(Self::#field_name(#value))}
         },
     );
@@ -107,7 +140,9 @@ fn enum_tokenizable_impl(
             },
         )| {
             let u8_discriminant = discriminant as u8;
-            if field_type.is_unit() {
+            if c_like {
+                quote! { Self::#field_name => (#u8_discriminant, ().into_token())}
+            } else if field_type.is_unit() {
                 quote! { Self::#field_name() => (#u8_discriminant, ().into_token())}
             } else {
                 quote! { Self::#field_name(inner) => (#u8_discriminant, inner.into_token())}
@@ -158,6 +193,39 @@ fn enum_tokenizable_impl(
     }
 }
 
+fn enum_discriminant_conversions(enum_ident: &Ident, components: &[Component]) -> TokenStream {
+    let enum_ident_stringified = enum_ident.to_string();
+
+    let (discriminants, variants): (Vec<_>, Vec<_>) = components
+        .iter()
+        .enumerate()
+        .map(|(discriminant, component)| (discriminant as u64, &component.field_name))
+        .unzip();
+
+    quote! {
+        impl TryFrom<u64> for #enum_ident {
+            type Error = SDKError;
+
+            fn try_from(discriminant: u64) -> Result<Self, Self::Error> {
+                match discriminant {
+                    #(#discriminants => Ok(Self::#variants),)*
+                    _ => Err(SDKError::InvalidData(format!(
+                        "Discriminant {} doesn't point to any of the {} variants.", discriminant, #enum_ident_stringified
+                    ))),
+                }
+            }
+        }
+
+        impl From<#enum_ident> for u64 {
+            fn from(value: #enum_ident) -> Self {
+                match value {
+                    #(#enum_ident::#variants => #discriminants,)*
+                }
+            }
+        }
+    }
+}
+
 fn enum_parameterize_impl(
     enum_ident: &Ident,
     components: &[Component],
@@ -68,13 +68,113 @@ pub fn expand_function(
                 &self.wallet,
                 encoded_fn_selector,
                 &tokens,
-                log_decoder
+                log_decoder,
+                self.mock.clone()
             )
             .expect("method not found (this should never happen)")
         }
     })
 }
 
+/// Returns the declaration of the given function as a method of the trait
+/// generated for the ABI, alongside its implementation for the contract
+/// instance, which delegates the call to the instance's `methods()`.
+pub fn expand_trait_function(
+    function: &ABIFunction,
+    types: &HashMap<usize, TypeDeclaration>,
+) -> Result<(TokenStream, TokenStream), Error> {
+    if function.name.is_empty() {
+        return Err(Error::InvalidData("Function name can not be empty".into()));
+    }
+
+    let args = function_arguments(function, types)?;
+
+    let arg_names = args.iter().map(|component| &component.field_name);
+
+    let arg_declarations = args.iter().map(|component| {
+        let name = &component.field_name;
+        let field_type: TokenStream = (&component.field_type).into();
+        quote! { #name: #field_type }
+    });
+
+    let doc = expand_doc(&format!(
+        "Calls the contract's `{}` function",
+        function.name,
+    ));
+
+    let name = safe_ident(&function.name);
+
+    let output_type: TokenStream = resolve_fn_output_type(function, types)?.into();
+
+    let signature = quote! {
+        fn #name(&self #(,#arg_declarations)*) -> ContractCallHandler<#output_type>
+    };
+
+    Ok((
+        quote! {
+            #doc
+            #signature;
+        },
+        quote! {
+            #signature {
+                self.methods().#name(#(#arg_names),*)
+            }
+        },
+    ))
+}
+
+/// Returns the method of the generated contract mock registering an expected
+/// call of the given function with the given arguments.
+pub fn expand_mock_function(
+    function: &ABIFunction,
+    types: &HashMap<usize, TypeDeclaration>,
+) -> Result<TokenStream, Error> {
+    if function.name.is_empty() {
+        return Err(Error::InvalidData("Function name can not be empty".into()));
+    }
+
+    let args = function_arguments(function, types)?;
+
+    let arg_names = args.iter().map(|component| &component.field_name);
+
+    let param_type_calls = param_type_calls(&args);
+
+    let arg_declarations = args.iter().map(|component| {
+        let name = &component.field_name;
+        let field_type: TokenStream = (&component.field_type).into();
+        quote! { #name: #field_type }
+    });
+
+    let doc = expand_doc(&format!(
+        "Expects a call to the contract's `{}` function with the given arguments",
+        function.name,
+    ));
+
+    let name = safe_ident(&format!("expect_{}", function.name));
+    // Must match the name the selector is computed from in `expand_function`
+    let name_stringified = safe_ident(&function.name).to_string();
+
+    let output_type: TokenStream = resolve_fn_output_type(function, types)?.into();
+
+    Ok(quote! {
+        #doc
+        pub fn #name(&self #(,#arg_declarations)*) -> MockCall<#output_type> {
+            let encoded_fn_selector = resolve_fn_selector(#name_stringified, &[#(#param_type_calls),*]);
+            let tokens = [#(#arg_names.into_token()),*];
+            let log_decoder = LogDecoder{logs_map: self.logs_map.clone()};
+            MockCall::new(
+                self.mock.clone(),
+                self.contract_id.clone(),
+                #name_stringified,
+                encoded_fn_selector,
+                &tokens,
+                log_decoder
+            )
+            .expect("failed to encode the expected arguments")
+        }
+    })
+}
+
 /// Generate the `main` function of a script
 pub fn generate_script_main_function(
     main_function_abi: &ABIFunction,
@@ -91,14 +191,6 @@ pub fn generate_script_main_function(
     let output_type: TokenStream = output_type_resolved.into();
 
     let args = function_arguments(main_function_abi, types)?;
-
-    // TODO(iqdecay): enable support for vector inputs
-    if args.iter().any(|c| c.field_type.uses_vectors()) {
-        return Err(Error::CompilationError(
-            "Script main function contains a vector in its argument types. This currently isn't supported."
-                .to_string(),
-        ));
-    }
     let arg_names = args.iter().map(|component| &component.field_name);
 
     let arg_declarations = args.iter().map(|component| {
@@ -113,23 +205,55 @@ pub fn generate_script_main_function(
 
     Ok(quote! {
         #doc
-        pub fn #name(&self #(,#arg_declarations)*) -> ScriptCallHandler<#output_type> {
+        pub fn #name(&self #(,#arg_declarations)*) -> Result<ScriptCallHandler<#output_type>, SDKError> {
             let arg_name_tokens = [#(#arg_names.into_token()),*];
-            let script_data = ABIEncoder::encode(&arg_name_tokens).expect("Cannot encode script
-            arguments").resolve(0);
-            let script_binary = std::fs::read(self.binary_filepath.as_str())
-                                        .expect("Could not read from binary filepath");
-            let provider = self.wallet.get_provider().expect("Provider not set up").clone();
-            // TODO(iqdecay): handle log decoding in scripts
-            let log_decoder = LogDecoder::default();
-            ScriptCallHandler::new(
+            let script_data = ABIEncoder::encode(&arg_name_tokens)?;
+            let mut script_binary = self.binary.bytes()?;
+            self.configurables.update_constants_in(&mut script_binary)?;
+            let provider = self.wallet.get_provider()?.clone();
+            let log_decoder = LogDecoder { logs_map: self.logs_map.clone() };
+            Ok(ScriptCallHandler::new(
                 script_binary,
                 script_data,
                 self.wallet.clone(),
                 provider,
                 #output_params,
                 log_decoder
-            )
+            ))
+        }
+    })
+}
+
+/// Generates `encode_data`, which encodes the arguments of a predicate's
+/// `main` function into the data handed to the predicate when spending it.
+pub fn generate_predicate_encode_function(
+    main_function_abi: &ABIFunction,
+    types: &HashMap<usize, TypeDeclaration>,
+) -> Result<TokenStream, Error> {
+    let args = function_arguments(main_function_abi, types)?;
+
+    if args.iter().any(|c| c.field_type.uses_vectors()) {
+        return Err(Error::CompilationError(
+            "Predicate main function contains a vector in its argument types. This currently isn't supported."
+                .to_string(),
+        ));
+    }
+    let arg_names = args.iter().map(|component| &component.field_name);
+
+    let arg_declarations = args.iter().map(|component| {
+        let name = &component.field_name;
+        let field_type: TokenStream = (&component.field_type).into();
+        quote! { #name: #field_type }
+    });
+
+    let doc =
+        expand_doc("Encode the arguments of the predicate's `main` function into predicate data");
+
+    Ok(quote! {
+        #doc
+        pub fn encode_data(&self #(,#arg_declarations)*) -> Result<Vec<u8>, SDKError> {
+            let arg_name_tokens = [#(#arg_names.into_token()),*];
+            Ok(ABIEncoder::encode(&arg_name_tokens)?.resolve(0))
         }
     })
 }
@@ -347,7 +471,8 @@ mod tests {
                         &self.wallet,
                         encoded_fn_selector,
                         &tokens,
-                        log_decoder
+                        log_decoder,
+                        self.mock.clone()
                     )
                     .expect("method not found (this should never happen)")
                 }
@@ -406,7 +531,8 @@ mod tests {
                     &self.wallet,
                     encoded_fn_selector,
                     &tokens,
-                    log_decoder
+                    log_decoder,
+                    self.mock.clone()
                 )
                 .expect("method not found (this should never happen)")
             }
@@ -418,6 +544,180 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_expand_trait_function() -> Result<(), Error> {
+        let the_function = ABIFunction {
+            inputs: vec![TypeApplication {
+                name: String::from("bimbam"),
+                type_id: 1,
+                ..Default::default()
+            }],
+            name: "HelloWorld".to_string(),
+            ..Default::default()
+        };
+        let types = [
+            (
+                0,
+                TypeDeclaration {
+                    type_id: 0,
+                    type_field: String::from("()"),
+                    ..Default::default()
+                },
+            ),
+            (
+                1,
+                TypeDeclaration {
+                    type_id: 1,
+                    type_field: String::from("bool"),
+                    ..Default::default()
+                },
+            ),
+        ]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+        let (declaration, implementation) = expand_trait_function(&the_function, &types)?;
+
+        let expected_declaration = TokenStream::from_str(
+            r#"
+            #[doc = "Calls the contract's `HelloWorld` function"]
+            fn HelloWorld(&self, bimbam: bool) -> ContractCallHandler<()> ;
+            "#,
+        )?;
+        let expected_implementation = TokenStream::from_str(
+            r#"
+            fn HelloWorld(&self, bimbam: bool) -> ContractCallHandler<()> {
+                self.methods().HelloWorld(bimbam)
+            }
+            "#,
+        )?;
+
+        assert_eq!(declaration.to_string(), expected_declaration.to_string());
+        assert_eq!(
+            implementation.to_string(),
+            expected_implementation.to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_expand_mock_function() -> Result<(), Error> {
+        let the_function = ABIFunction {
+            inputs: vec![TypeApplication {
+                name: String::from("bimbam"),
+                type_id: 1,
+                ..Default::default()
+            }],
+            name: "HelloWorld".to_string(),
+            ..Default::default()
+        };
+        let types = [
+            (
+                0,
+                TypeDeclaration {
+                    type_id: 0,
+                    type_field: String::from("()"),
+                    ..Default::default()
+                },
+            ),
+            (
+                1,
+                TypeDeclaration {
+                    type_id: 1,
+                    type_field: String::from("bool"),
+                    ..Default::default()
+                },
+            ),
+        ]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+        let result = expand_mock_function(&the_function, &types);
+        let expected = TokenStream::from_str(
+            r#"
+            #[doc = "Expects a call to the contract's `HelloWorld` function with the given arguments"]
+            pub fn expect_HelloWorld(&self, bimbam: bool) -> MockCall<()> {
+                let encoded_fn_selector = resolve_fn_selector("HelloWorld", &[<bool> :: param_type()]);
+                let tokens = [bimbam.into_token()];
+                let log_decoder = LogDecoder{logs_map: self.logs_map.clone()};
+                MockCall::new(
+                    self.mock.clone(),
+                    self.contract_id.clone(),
+                    "HelloWorld",
+                    encoded_fn_selector,
+                    &tokens,
+                    log_decoder
+                )
+                .expect("failed to encode the expected arguments")
+            }
+            "#,
+        );
+        let expected = expected?.to_string();
+
+        assert_eq!(result?.to_string(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_predicate_encode_function() -> Result<(), Error> {
+        let the_function = ABIFunction {
+            inputs: vec![
+                TypeApplication {
+                    name: String::from("has_account"),
+                    type_id: 1,
+                    ..Default::default()
+                },
+                TypeApplication {
+                    name: String::from("total_complete"),
+                    type_id: 2,
+                    ..Default::default()
+                },
+            ],
+            name: "main".to_string(),
+            ..Default::default()
+        };
+        let types = [
+            (
+                0,
+                TypeDeclaration {
+                    type_id: 0,
+                    type_field: String::from("bool"),
+                    ..Default::default()
+                },
+            ),
+            (
+                1,
+                TypeDeclaration {
+                    type_id: 1,
+                    type_field: String::from("bool"),
+                    ..Default::default()
+                },
+            ),
+            (
+                2,
+                TypeDeclaration {
+                    type_id: 2,
+                    type_field: String::from("u64"),
+                    ..Default::default()
+                },
+            ),
+        ]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+        let result = generate_predicate_encode_function(&the_function, &types);
+        let expected = TokenStream::from_str(
+            r#"
+            #[doc = "Encode the arguments of the predicate's `main` function into predicate data"]
+            pub fn encode_data(&self, has_account: bool, total_complete: u64) -> Result<Vec<u8> , SDKError> {
+                let arg_name_tokens = [has_account.into_token(), total_complete.into_token()];
+                Ok(ABIEncoder::encode(&arg_name_tokens)? .resolve(0))
+            }
+            "#,
+        );
+        let expected = expected?.to_string();
+
+        assert_eq!(result?.to_string(), expected);
+        Ok(())
+    }
+
     #[test]
     fn test_expand_function_complex() -> Result<(), Error> {
         let the_function = ABIFunction {
@@ -512,7 +812,8 @@ mod tests {
                     &self.wallet,
                     encoded_fn_selector,
                     &tokens,
-                    log_decoder
+                    log_decoder,
+                    self.mock.clone()
                 )
                 .expect("method not found (this should never happen)")
             }
@@ -0,0 +1,67 @@
+use fuels_types::errors::Error;
+
+/// Values of the configurable constants of a program, each encoded and
+/// paired with the offset of the constant in the program's binary.
+///
+/// The `abigen!` macros generate a typed builder for every program, which
+/// converts into `Configurables`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Configurables {
+    offsets_with_data: Vec<(u64, Vec<u8>)>,
+}
+
+impl Configurables {
+    pub fn new(offsets_with_data: Vec<(u64, Vec<u8>)>) -> Self {
+        Self { offsets_with_data }
+    }
+
+    /// Writes the values of the constants into `binary`, overwriting the
+    /// ones it was compiled with.
+    pub fn update_constants_in(&self, binary: &mut [u8]) -> Result<(), Error> {
+        for (offset, data) in &self.offsets_with_data {
+            let start = *offset as usize;
+            let end = start + data.len();
+            let binary_len = binary.len();
+
+            let constant = binary.get_mut(start..end).ok_or_else(|| {
+                Error::InvalidData(format!(
+                    "configurable constant at offset {offset} doesn't fit in a binary of {binary_len} bytes"
+                ))
+            })?;
+            constant.copy_from_slice(data);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constants_are_written_at_their_offset() -> Result<(), Error> {
+        let configurables = Configurables::new(vec![(2, vec![7, 7]), (5, vec![9])]);
+        let mut binary = vec![0; 6];
+
+        configurables.update_constants_in(&mut binary)?;
+
+        assert_eq!(binary, [0, 0, 7, 7, 0, 9]);
+        Ok(())
+    }
+
+    #[test]
+    fn constants_outside_of_the_binary_are_rejected() {
+        let configurables = Configurables::new(vec![(4, vec![1, 2, 3])]);
+        let mut binary = vec![0; 6];
+
+        let err = configurables
+            .update_constants_in(&mut binary)
+            .expect_err("should fail");
+
+        assert!(err
+            .to_string()
+            .contains("configurable constant at offset 4 doesn't fit in a binary of 6 bytes"));
+        assert_eq!(binary, [0; 6]);
+    }
+}
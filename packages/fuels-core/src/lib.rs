@@ -11,6 +11,7 @@ use strum_macros::EnumString;
 pub mod abi_decoder;
 pub mod abi_encoder;
 pub mod code_gen;
+pub mod configurables;
 pub mod constants;
 pub mod parameters;
 pub mod rustfmt;
@@ -1,12 +1,14 @@
+use crate::configurables::Configurables;
 use crate::constants::{
-    BASE_ASSET_ID, DEFAULT_CALL_PARAMS_AMOUNT, DEFAULT_GAS_LIMIT, DEFAULT_GAS_PRICE,
-    DEFAULT_MATURITY,
+    BASE_ASSET_ID, DEFAULT_CALL_PARAMS_AMOUNT, DEFAULT_GAS_LIMIT, DEFAULT_MATURITY,
 };
 use fuel_tx::{AssetId, StorageSlot};
 
 #[derive(Debug, Copy, Clone)]
 pub struct TxParameters {
-    pub gas_price: u64,
+    /// `None` lets the gas price strategy of the provider set the gas price, see
+    /// `Provider::apply_gas_price_strategy`
+    pub gas_price: Option<u64>,
     pub gas_limit: u64,
     pub maturity: u64,
 }
@@ -41,7 +43,7 @@ impl Default for CallParameters {
 impl Default for TxParameters {
     fn default() -> Self {
         Self {
-            gas_price: DEFAULT_GAS_PRICE,
+            gas_price: None,
             gas_limit: DEFAULT_GAS_LIMIT,
             // By default, transaction is immediately valid
             maturity: DEFAULT_MATURITY,
@@ -52,7 +54,7 @@ impl Default for TxParameters {
 impl TxParameters {
     pub fn new(gas_price: Option<u64>, gas_limit: Option<u64>, maturity: Option<u64>) -> Self {
         Self {
-            gas_price: gas_price.unwrap_or(DEFAULT_GAS_PRICE),
+            gas_price,
             gas_limit: gas_limit.unwrap_or(DEFAULT_GAS_LIMIT),
             maturity: maturity.unwrap_or(DEFAULT_MATURITY),
         }
@@ -87,3 +89,30 @@ impl StorageConfiguration {
         }
     }
 }
+
+/// Parameters used by the `deploy` functions generated by `abigen!` for
+/// contracts built with forc.
+#[derive(Debug, Default)]
+pub struct DeployConfig {
+    pub tx_parameters: TxParameters,
+    /// Storage slots to deploy the contract with. If no storage path is given,
+    /// the storage slots the contract was compiled with are used.
+    pub storage_configuration: StorageConfiguration,
+    /// Values of the configurable constants, written into the bytecode before deploying it.
+    pub configurables: Configurables,
+}
+
+impl DeployConfig {
+    pub fn new(tx_parameters: TxParameters, storage_configuration: StorageConfiguration) -> Self {
+        Self {
+            tx_parameters,
+            storage_configuration,
+            configurables: Configurables::default(),
+        }
+    }
+
+    pub fn with_configurables(mut self, configurables: impl Into<Configurables>) -> Self {
+        self.configurables = configurables.into();
+        self
+    }
+}
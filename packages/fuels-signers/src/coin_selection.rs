@@ -0,0 +1,163 @@
+use fuel_gql_client::client::schema::coin::Coin;
+use std::fmt::Debug;
+
+/// Selects the coins a wallet spends for its transactions, among its spendable coins fetched
+/// with [`Provider::get_coins`](crate::provider::Provider::get_coins), instead of the node.
+///
+/// Implement this trait to select coins differently than with the strategies of this module.
+pub trait CoinSelector: Debug + Send + Sync {
+    /// Returns coins of `coins` adding up at least to `amount`, `None` if they can't be
+    /// selected, e.g. because they don't add up to `amount`.
+    fn select(&self, coins: Vec<Coin>, amount: u64) -> Option<Vec<Coin>>;
+}
+
+/// Selects the largest coins first, spending as few coins as possible.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LargestFirst;
+
+impl CoinSelector for LargestFirst {
+    fn select(&self, mut coins: Vec<Coin>, amount: u64) -> Option<Vec<Coin>> {
+        coins.sort_by_key(|coin| std::cmp::Reverse(coin.amount.0));
+
+        take_until(coins, amount)
+    }
+}
+
+/// Selects the smallest coins first, spending the dust before the larger coins.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SmallestFirst;
+
+impl CoinSelector for SmallestFirst {
+    fn select(&self, mut coins: Vec<Coin>, amount: u64) -> Option<Vec<Coin>> {
+        coins.sort_by_key(|coin| coin.amount.0);
+
+        take_until(coins, amount)
+    }
+}
+
+/// Selects a single coin of exactly `amount` if there is one, so that no change is left.
+/// Otherwise, selects the smallest coin covering `amount` on its own, and falls back to
+/// [`LargestFirst`] if there is none.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExactMatchPreferred;
+
+impl CoinSelector for ExactMatchPreferred {
+    fn select(&self, mut coins: Vec<Coin>, amount: u64) -> Option<Vec<Coin>> {
+        coins.sort_by_key(|coin| coin.amount.0);
+
+        // The first coin covering `amount` is an exact match if there is one
+        match coins.iter().position(|coin| coin.amount.0 >= amount) {
+            Some(index) if amount > 0 => Some(vec![coins.swap_remove(index)]),
+            _ => LargestFirst.select(coins, amount),
+        }
+    }
+}
+
+/// Selects at most `max_inputs` coins, the smallest ones able to add up to `amount` within
+/// that bound, spending the dust while keeping the number of inputs under control.
+#[derive(Debug, Clone, Copy)]
+pub struct MaxInputs {
+    pub max_inputs: usize,
+}
+
+impl CoinSelector for MaxInputs {
+    fn select(&self, mut coins: Vec<Coin>, amount: u64) -> Option<Vec<Coin>> {
+        if amount == 0 {
+            return Some(vec![]);
+        }
+        coins.sort_by_key(|coin| coin.amount.0);
+
+        // Among the first coins, the largest `max_inputs` ones add up to the most: the
+        // selection is them, for the fewest first coins adding up to `amount`
+        let mut sum = 0u64;
+        for end in 0..coins.len() {
+            sum = sum.saturating_add(coins[end].amount.0);
+            let start = (end + 1).saturating_sub(self.max_inputs);
+            if start > 0 {
+                sum -= coins[start - 1].amount.0;
+            }
+
+            if start <= end && sum >= amount {
+                return Some(coins.drain(start..=end).collect());
+            }
+        }
+
+        None
+    }
+}
+
+/// Takes the coins in order until they add up to `amount`, `None` if they all don't.
+fn take_until(coins: Vec<Coin>, amount: u64) -> Option<Vec<Coin>> {
+    let mut sum = 0u64;
+    let mut selected = vec![];
+    for coin in coins {
+        if sum >= amount {
+            break;
+        }
+        sum = sum.saturating_add(coin.amount.0);
+        selected.push(coin);
+    }
+
+    (sum >= amount).then_some(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_gql_client::client::schema::coin::CoinStatus;
+
+    fn coins(amounts: &[u64]) -> Vec<Coin> {
+        amounts
+            .iter()
+            .map(|amount| Coin {
+                amount: (*amount).into(),
+                block_created: 0u64.into(),
+                asset_id: Default::default(),
+                utxo_id: Default::default(),
+                maturity: 0u64.into(),
+                owner: Default::default(),
+                status: CoinStatus::Unspent,
+            })
+            .collect()
+    }
+
+    fn selected_amounts(selector: impl CoinSelector, amount: u64) -> Option<Vec<u64>> {
+        let selection = selector.select(coins(&[5, 30, 1, 10, 20]), amount)?;
+
+        Some(selection.iter().map(|coin| coin.amount.0).collect())
+    }
+
+    #[test]
+    fn largest_coins_are_selected_first() {
+        assert_eq!(selected_amounts(LargestFirst, 40), Some(vec![30, 20]));
+        assert_eq!(selected_amounts(LargestFirst, 67), None);
+    }
+
+    #[test]
+    fn smallest_coins_are_selected_first() {
+        assert_eq!(selected_amounts(SmallestFirst, 12), Some(vec![1, 5, 10]));
+        assert_eq!(selected_amounts(SmallestFirst, 0), Some(vec![]));
+        assert_eq!(selected_amounts(SmallestFirst, 67), None);
+    }
+
+    #[test]
+    fn exact_matches_are_selected_first() {
+        assert_eq!(selected_amounts(ExactMatchPreferred, 10), Some(vec![10]));
+        assert_eq!(selected_amounts(ExactMatchPreferred, 12), Some(vec![20]));
+        assert_eq!(
+            selected_amounts(ExactMatchPreferred, 45),
+            Some(vec![30, 20])
+        );
+    }
+
+    #[test]
+    fn number_of_inputs_is_bounded() {
+        let max_inputs = |max_inputs| MaxInputs { max_inputs };
+
+        assert_eq!(selected_amounts(max_inputs(3), 12), Some(vec![1, 5, 10]));
+        assert_eq!(selected_amounts(max_inputs(2), 12), Some(vec![5, 10]));
+        assert_eq!(selected_amounts(max_inputs(2), 35), Some(vec![20, 30]));
+        assert_eq!(selected_amounts(max_inputs(1), 35), None);
+        assert_eq!(selected_amounts(max_inputs(0), 1), None);
+    }
+}
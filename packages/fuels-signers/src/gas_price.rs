@@ -0,0 +1,134 @@
+use crate::provider::{Provider, ProviderError};
+use async_trait::async_trait;
+use fuel_gql_client::{
+    client::{types::TransactionResponse, PageDirection, PaginationRequest},
+    fuel_tx::{field::GasPrice, Transaction},
+};
+use std::fmt::Debug;
+
+/// Decides the gas price of the transactions sent through a [`Provider`], when their
+/// transaction parameters don't set one.
+///
+/// Implement this trait to price transactions differently than with the strategies of this
+/// module, e.g. from an external gas price oracle.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait GasPriceStrategy: Debug + Send + Sync {
+    /// Returns the gas price to use for the next transaction
+    async fn gas_price(&self, provider: &Provider) -> Result<u64, ProviderError>;
+}
+
+/// Always uses the same gas price.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedGasPrice(pub u64);
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl GasPriceStrategy for FixedGasPrice {
+    async fn gas_price(&self, _provider: &Provider) -> Result<u64, ProviderError> {
+        Ok(self.0)
+    }
+}
+
+/// Uses the minimum gas price accepted by the node. This is the default strategy.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NodeMinGasPrice;
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl GasPriceStrategy for NodeMinGasPrice {
+    async fn gas_price(&self, provider: &Provider) -> Result<u64, ProviderError> {
+        Ok(provider.node_info().await?.min_gas_price.0)
+    }
+}
+
+/// Uses the minimum gas price accepted by the node, multiplied by `multiplier` and rounded up.
+#[derive(Debug, Clone, Copy)]
+pub struct MinGasPriceMultiplier {
+    pub multiplier: f64,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl GasPriceStrategy for MinGasPriceMultiplier {
+    async fn gas_price(&self, provider: &Provider) -> Result<u64, ProviderError> {
+        let min_gas_price = NodeMinGasPrice.gas_price(provider).await?;
+
+        Ok((min_gas_price as f64 * self.multiplier).ceil() as u64)
+    }
+}
+
+/// Uses the `percentile` (from 0 to 100) of the gas prices paid by the transactions of the
+/// last `blocks` blocks. Falls back to the minimum gas price accepted by the node if it is
+/// higher, or if these blocks have no priced transactions.
+#[derive(Debug, Clone, Copy)]
+pub struct RecentBlocksPercentile {
+    pub blocks: usize,
+    pub percentile: u8,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl GasPriceStrategy for RecentBlocksPercentile {
+    async fn gas_price(&self, provider: &Provider) -> Result<u64, ProviderError> {
+        let blocks = provider
+            .get_blocks(PaginationRequest {
+                cursor: None,
+                results: self.blocks,
+                direction: PageDirection::Backward,
+            })
+            .await?
+            .results;
+
+        let mut gas_prices = vec![];
+        for tx_id in blocks.iter().flat_map(|block| block.transactions()) {
+            let response = provider.client.transaction(&tx_id.to_string()).await?;
+
+            match response {
+                Some(TransactionResponse {
+                    transaction: Transaction::Script(tx),
+                    ..
+                }) => gas_prices.push(*tx.gas_price()),
+                Some(TransactionResponse {
+                    transaction: Transaction::Create(tx),
+                    ..
+                }) => gas_prices.push(*tx.gas_price()),
+                _ => {}
+            }
+        }
+
+        let min_gas_price = NodeMinGasPrice.gas_price(provider).await?;
+
+        Ok(percentile(gas_prices, self.percentile)
+            .map_or(min_gas_price, |gas_price| gas_price.max(min_gas_price)))
+    }
+}
+
+/// Returns the value under which `percentile` percent of the `values` are, `None` if there are
+/// no values.
+fn percentile(mut values: Vec<u64>, percentile: u8) -> Option<u64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    values.sort_unstable();
+    let index = (values.len() - 1) * usize::from(percentile.min(100)) / 100;
+
+    Some(values[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_of_gas_prices() {
+        let gas_prices = vec![50, 10, 40, 20, 30];
+
+        assert_eq!(percentile(gas_prices.clone(), 0), Some(10));
+        assert_eq!(percentile(gas_prices.clone(), 50), Some(30));
+        assert_eq!(percentile(gas_prices.clone(), 100), Some(50));
+        assert_eq!(percentile(gas_prices, 200), Some(50));
+        assert_eq!(percentile(vec![], 50), None);
+    }
+}
@@ -1,6 +1,12 @@
 extern crate core;
 
+pub mod coin_selection;
+pub mod gas_price;
+pub mod pending_transaction;
 pub mod provider;
+pub mod resource_tracker;
+pub mod subscriptions;
+pub mod tx_builders;
 pub mod wallet;
 
 #[doc(no_inline)]
@@ -177,7 +183,7 @@ mod tests {
         let maturity = 0;
 
         let tx_params = TxParameters {
-            gas_price,
+            gas_price: Some(gas_price),
             gas_limit,
             maturity,
         };
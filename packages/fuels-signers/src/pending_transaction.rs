@@ -0,0 +1,140 @@
+use crate::{
+    provider::{Provider, ProviderError},
+    resource_tracker::ResourceTracker,
+};
+use fuel_gql_client::{
+    client::types::TransactionStatus,
+    fuel_tx::{Bytes32, Input, Receipt},
+};
+use fuels_types::errors::Error;
+use std::time::Duration;
+
+/// Handle to a transaction submitted to the node with [`Provider::submit`], which may not be
+/// committed to a block yet. It can be used to follow the transaction while doing other work,
+/// e.g. submitting other transactions.
+///
+/// The resources reserved for the transaction, see
+/// [`with_reserved_resources`](Self::with_reserved_resources), are released once a final status
+/// is seen by [`await_commit`](Self::await_commit), [`status`](Self::status) or
+/// [`receipts`](Self::receipts). If it is dropped before that, they stay reserved: release them
+/// with [`release_resources`](Self::release_resources) instead.
+#[derive(Debug, Clone)]
+pub struct PendingTransaction {
+    tx_id: Bytes32,
+    provider: Provider,
+    reserved_resources: Option<(ResourceTracker, Vec<Input>)>,
+}
+
+impl PendingTransaction {
+    pub fn new(tx_id: Bytes32, provider: Provider) -> Self {
+        Self {
+            tx_id,
+            provider,
+            reserved_resources: None,
+        }
+    }
+
+    /// Releases the resources spent by `inputs` from `tracker` once the transaction is seen
+    /// committed or squeezed out.
+    pub fn with_reserved_resources(mut self, tracker: ResourceTracker, inputs: Vec<Input>) -> Self {
+        self.reserved_resources = Some((tracker, inputs));
+        self
+    }
+
+    pub fn tx_id(&self) -> Bytes32 {
+        self.tx_id
+    }
+
+    /// Returns the current status of the transaction.
+    pub async fn status(&self) -> Result<TransactionStatus, Error> {
+        let status = self
+            .provider
+            .client
+            .transaction_status(&self.tx_id.to_string())
+            .await
+            .map_err(ProviderError::from)?;
+        self.release_if_final(&status).await;
+
+        Ok(status)
+    }
+
+    /// Releases the resources reserved for the transaction, if any. Needed only if the
+    /// transaction is given up on before its final status is seen.
+    pub async fn release_resources(&self) {
+        if let Some((tracker, inputs)) = &self.reserved_resources {
+            tracker.release(inputs).await;
+        }
+    }
+
+    async fn release_if_final(&self, status: &TransactionStatus) {
+        if !matches!(status, TransactionStatus::Submitted { .. }) {
+            self.release_resources().await;
+        }
+    }
+
+    /// Waits for the transaction to be committed, or squeezed out, and returns its final
+    /// status. If the transaction is still pending after `timeout`, an error is returned,
+    /// without `timeout` this waits as long as needed.
+    pub async fn await_commit(
+        &self,
+        timeout: Option<Duration>,
+    ) -> Result<TransactionStatus, Error> {
+        let tx_id = self.tx_id.to_string();
+        let commit = self.provider.client.await_transaction_commit(&tx_id);
+
+        let status = match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, commit).await.map_err(|_| {
+                Error::ProviderError(format!(
+                    "transaction {} was not committed within {timeout:?}",
+                    self.tx_id
+                ))
+            })?,
+            None => commit.await,
+        }
+        .map_err(ProviderError::from)?;
+        self.release_if_final(&status).await;
+
+        Ok(status)
+    }
+
+    /// Returns the receipts of the committed transaction. An error is returned if the
+    /// transaction is not committed yet, or if it was squeezed out. If it reverted, the error is
+    /// a [`RevertTransactionError`](Error::RevertTransactionError) holding its receipts.
+    pub async fn receipts(&self) -> Result<Vec<Receipt>, Error> {
+        let status = self.status().await?;
+
+        self.receipts_with_status(status).await
+    }
+
+    pub(crate) async fn receipts_with_status(
+        &self,
+        status: TransactionStatus,
+    ) -> Result<Vec<Receipt>, Error> {
+        self.release_if_final(&status).await;
+
+        match status {
+            TransactionStatus::Submitted { .. } => Err(Error::ProviderError(format!(
+                "transaction {} is not committed yet",
+                self.tx_id
+            ))),
+            TransactionStatus::SqueezedOut { reason } => Err(Error::ProviderError(format!(
+                "transaction {} was squeezed out: {reason}",
+                self.tx_id
+            ))),
+            TransactionStatus::Failure { reason, .. } => Err(Error::RevertTransactionError(
+                reason,
+                self.fetch_receipts().await?,
+            )),
+            TransactionStatus::Success { .. } => self.fetch_receipts().await,
+        }
+    }
+
+    async fn fetch_receipts(&self) -> Result<Vec<Receipt>, Error> {
+        Ok(self
+            .provider
+            .client
+            .receipts(&self.tx_id.to_string())
+            .await
+            .map_err(ProviderError::from)?)
+    }
+}
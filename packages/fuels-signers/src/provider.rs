@@ -3,7 +3,7 @@ use std::io;
 #[cfg(feature = "fuel-core")]
 use fuel_core::service::{Config, FuelService};
 
-use fuel_gql_client::client::types::{TransactionResponse, TransactionStatus};
+use fuel_gql_client::client::types::TransactionResponse;
 use fuel_gql_client::interpreter::ExecutableTransaction;
 use fuel_gql_client::{
     client::{
@@ -14,15 +14,20 @@ use fuel_gql_client::{
         },
         FuelClient, PageDirection, PaginatedResult, PaginationRequest,
     },
-    fuel_tx::{Receipt, Transaction, TransactionFee},
-    fuel_types::AssetId,
+    fuel_tx::{Receipt, Transaction, TransactionFee, UtxoId},
+    fuel_types::{AssetId, MessageId},
+};
+use fuels_core::{
+    constants::{DEFAULT_GAS_ESTIMATION_TOLERANCE, MAX_GAS_PER_TX},
+    parameters::TxParameters,
 };
-use fuels_core::constants::{DEFAULT_GAS_ESTIMATION_TOLERANCE, MAX_GAS_PER_TX};
 use fuels_types::block::Block;
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 use thiserror::Error;
 
-use crate::{field, UniqueIdentifier};
+use crate::field;
+use crate::gas_price::{GasPriceStrategy, NodeMinGasPrice};
+use crate::pending_transaction::PendingTransaction;
 use fuels_types::bech32::{Bech32Address, Bech32ContractId};
 use fuels_types::errors::Error;
 
@@ -75,11 +80,48 @@ impl From<ProviderError> for Error {
 #[derive(Debug, Clone)]
 pub struct Provider {
     pub client: FuelClient,
+    gas_price_strategy: Arc<dyn GasPriceStrategy>,
 }
 
 impl Provider {
     pub fn new(client: FuelClient) -> Self {
-        Self { client }
+        Self {
+            client,
+            gas_price_strategy: Arc::new(NodeMinGasPrice),
+        }
+    }
+
+    /// Sets the [`GasPriceStrategy`] pricing the transactions whose parameters don't set a gas
+    /// price, [`NodeMinGasPrice`] by default.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// let provider = Provider::connect(url).await?.with_gas_price_strategy(FixedGasPrice(1));
+    /// ```
+    pub fn with_gas_price_strategy(mut self, strategy: impl GasPriceStrategy + 'static) -> Self {
+        self.gas_price_strategy = Arc::new(strategy);
+        self
+    }
+
+    /// Returns the gas price given by the [`GasPriceStrategy`] of the provider.
+    pub async fn gas_price(&self) -> Result<u64, ProviderError> {
+        self.gas_price_strategy.gas_price(self).await
+    }
+
+    /// Returns `tx_parameters` with the gas price given by the [`GasPriceStrategy`] of the
+    /// provider if they don't set one.
+    pub async fn apply_gas_price_strategy(
+        &self,
+        tx_parameters: TxParameters,
+    ) -> Result<TxParameters, ProviderError> {
+        if tx_parameters.gas_price.is_some() {
+            return Ok(tx_parameters);
+        }
+
+        Ok(TxParameters {
+            gas_price: Some(self.gas_price().await?),
+            ..tx_parameters
+        })
     }
 
     /// Sends a transaction to the underlying Provider's client.
@@ -102,6 +144,19 @@ impl Provider {
     /// }
     /// ```
     pub async fn send_transaction<Tx>(&self, tx: &Tx) -> Result<Vec<Receipt>, Error>
+    where
+        Tx: ExecutableTransaction + field::GasLimit + field::GasPrice + Into<Transaction>,
+    {
+        let pending_transaction = self.submit(tx).await?;
+        let status = pending_transaction.await_commit(None).await?;
+
+        pending_transaction.receipts_with_status(status).await
+    }
+
+    /// Submits a transaction to the underlying Provider's client without waiting for it to be
+    /// committed. The returned [`PendingTransaction`] follows the transaction and gives its
+    /// receipts once it is committed.
+    pub async fn submit<Tx>(&self, tx: &Tx) -> Result<PendingTransaction, Error>
     where
         Tx: ExecutableTransaction + field::GasLimit + field::GasPrice + Into<Transaction>,
     {
@@ -126,24 +181,12 @@ impl Provider {
             )));
         }
 
-        let (status, receipts) = self.submit_with_feedback(&tx.clone().into()).await?;
-
-        if let TransactionStatus::Failure { reason, .. } = status {
-            Err(Error::RevertTransactionError(reason, receipts))
-        } else {
-            Ok(receipts)
-        }
-    }
-
-    async fn submit_with_feedback(
-        &self,
-        tx: &Transaction,
-    ) -> Result<(TransactionStatus, Vec<Receipt>), ProviderError> {
-        let tx_id = tx.id().to_string();
-        let status = self.client.submit_and_await_commit(tx).await?;
-        let receipts = self.client.receipts(&tx_id).await?;
+        self.client
+            .submit(&tx.clone().into())
+            .await
+            .map_err(ProviderError::from)?;
 
-        Ok((status, receipts))
+        Ok(PendingTransaction::new(tx.id(), self.clone()))
     }
 
     #[cfg(feature = "fuel-core")]
@@ -239,12 +282,42 @@ impl Provider {
         asset_id: AssetId,
         amount: u64,
     ) -> Result<Vec<Resource>, ProviderError> {
+        self.get_spendable_resources_excluding(from, asset_id, amount, &[], &[])
+            .await
+    }
+
+    /// Same as [`get_spendable_resources`](Self::get_spendable_resources), without the coins
+    /// and messages whose ids are given.
+    pub async fn get_spendable_resources_excluding(
+        &self,
+        from: &Bech32Address,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_utxo_ids: &[UtxoId],
+        excluded_message_ids: &[MessageId],
+    ) -> Result<Vec<Resource>, ProviderError> {
+        let excluded_utxo_ids: Vec<_> = excluded_utxo_ids
+            .iter()
+            .map(|utxo_id| format!("{:#x}", utxo_id))
+            .collect();
+        let excluded_message_ids: Vec<_> = excluded_message_ids
+            .iter()
+            .map(|message_id| format!("{:#x}", message_id))
+            .collect();
+        let excluded_ids = (!excluded_utxo_ids.is_empty() || !excluded_message_ids.is_empty())
+            .then(|| {
+                (
+                    excluded_utxo_ids.iter().map(String::as_str).collect(),
+                    excluded_message_ids.iter().map(String::as_str).collect(),
+                )
+            });
+
         let res = self
             .client
             .resources_to_spend(
                 &from.hash().to_string(),
                 vec![(format!("{:#x}", asset_id).as_str(), amount, None)],
-                None,
+                excluded_ids,
             )
             .await?
             .into_iter()
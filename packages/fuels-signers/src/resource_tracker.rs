@@ -0,0 +1,88 @@
+use fuel_gql_client::{
+    client::schema::resource::Resource,
+    fuel_tx::{Input, UtxoId},
+    fuel_types::MessageId,
+};
+use fuels_types::errors::Error;
+use std::{collections::HashSet, future::Future, sync::Arc};
+use tokio::sync::Mutex;
+
+/// Reserves the resources (coins and messages) of a wallet selected for transactions that are
+/// not committed yet, so that concurrent transactions from the wallet don't spend the same
+/// resources.
+///
+/// A wallet tracks its resources once given a tracker with
+/// [`set_resource_tracker`](crate::wallet::Wallet::set_resource_tracker), which its clones
+/// share. The resources spent by a transaction are released once it is committed, its change
+/// outputs then being spendable, or if it couldn't be built, its submission failed or it was
+/// squeezed out. The resources of a transaction built with a transaction builder, e.g.
+/// [`ScriptTransactionBuilder`](crate::tx_builders::ScriptTransactionBuilder), and sent through
+/// the provider must be released with [`release`](Self::release).
+#[derive(Debug, Clone, Default)]
+pub struct ResourceTracker {
+    reserved: Arc<Mutex<ReservedResources>>,
+}
+
+#[derive(Debug, Default)]
+struct ReservedResources {
+    utxo_ids: HashSet<UtxoId>,
+    message_ids: HashSet<MessageId>,
+}
+
+impl ResourceTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of resources currently reserved.
+    pub async fn reserved_count(&self) -> usize {
+        let reserved = self.reserved.lock().await;
+
+        reserved.utxo_ids.len() + reserved.message_ids.len()
+    }
+
+    /// Reserves the resources returned by `select`, which is given the ids of the coins and
+    /// messages already reserved, to leave out.
+    pub async fn reserve<F, Fut>(&self, select: F) -> Result<Vec<Resource>, Error>
+    where
+        F: FnOnce(Vec<UtxoId>, Vec<MessageId>) -> Fut,
+        Fut: Future<Output = Result<Vec<Resource>, Error>>,
+    {
+        // The lock is held during the selection, so that concurrent selections can't get the
+        // same resources
+        let mut reserved = self.reserved.lock().await;
+
+        let excluded_utxo_ids = reserved.utxo_ids.iter().cloned().collect();
+        let excluded_message_ids = reserved.message_ids.iter().cloned().collect();
+        let resources = select(excluded_utxo_ids, excluded_message_ids).await?;
+
+        for resource in &resources {
+            match resource {
+                Resource::Coin(coin) => {
+                    reserved.utxo_ids.insert(coin.utxo_id.clone().into());
+                }
+                Resource::Message(message) => {
+                    reserved
+                        .message_ids
+                        .insert(message.message_id.clone().into());
+                }
+            }
+        }
+
+        Ok(resources)
+    }
+
+    /// Releases the reserved resources spent by `inputs`.
+    pub async fn release(&self, inputs: &[Input]) {
+        let mut reserved = self.reserved.lock().await;
+
+        for input in inputs {
+            if let Some(utxo_id) = input.utxo_id() {
+                reserved.utxo_ids.remove(utxo_id);
+            }
+            if let Some(message_id) = input.message_id() {
+                reserved.message_ids.remove(message_id);
+            }
+        }
+    }
+}
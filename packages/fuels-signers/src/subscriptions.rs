@@ -0,0 +1,150 @@
+use crate::provider::{Provider, ProviderError};
+use fuel_gql_client::client::{types::TransactionStatus, PageDirection, PaginationRequest};
+use fuels_types::{block::Block, errors::Error};
+use futures::{
+    future,
+    stream::{self, BoxStream},
+    StreamExt,
+};
+use std::{collections::VecDeque, mem::discriminant, time::Duration};
+
+/// Time waited between two queries by the streams polling the node.
+pub const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_millis(500);
+
+impl Provider {
+    /// Returns a stream of the statuses of the transaction `tx_id`, starting with its current
+    /// one. The stream ends after a final status: success, failure or squeezed out.
+    ///
+    /// The statuses are pushed by the node through a GraphQL subscription. If the node can't be
+    /// subscribed to, e.g. behind a proxy not supporting server-sent events, the stream falls
+    /// back to [`poll_transaction_status`](Self::poll_transaction_status) with the
+    /// [`DEFAULT_POLLING_INTERVAL`].
+    pub async fn subscribe_transaction_status<'a>(
+        &'a self,
+        tx_id: &'a str,
+    ) -> Result<BoxStream<'a, Result<TransactionStatus, Error>>, Error> {
+        let subscription = match self.client.subscribe_transaction_status(tx_id).await {
+            Ok(subscription) => subscription,
+            Err(_) => return Ok(self.poll_transaction_status(tx_id, DEFAULT_POLLING_INTERVAL)),
+        };
+
+        // Failing to connect only shows as the first item of the subscription
+        let mut subscription = subscription.boxed();
+        match subscription.next().await {
+            Some(Ok(status)) => Ok(stream::once(future::ready(Ok(status)))
+                .chain(subscription.map(|status| Ok(status.map_err(ProviderError::from)?)))
+                .boxed()),
+            _ => Ok(self.poll_transaction_status(tx_id, DEFAULT_POLLING_INTERVAL)),
+        }
+    }
+
+    /// Returns a stream of the statuses of the transaction `tx_id`, queried every `interval`.
+    /// Only the changes of status are yielded, starting with the current status, and the stream
+    /// ends after a final one. It also ends after the first error.
+    pub fn poll_transaction_status<'a>(
+        &'a self,
+        tx_id: &'a str,
+        interval: Duration,
+    ) -> BoxStream<'a, Result<TransactionStatus, Error>> {
+        stream::unfold(Some(None), move |last_status| async move {
+            // `None` once the stream is over, `Some(None)` before the first status
+            let last_status = last_status?;
+
+            loop {
+                if last_status.is_some() {
+                    tokio::time::sleep(interval).await;
+                }
+
+                let status = match self.client.transaction_status(tx_id).await {
+                    Ok(status) => status,
+                    Err(e) => return Some((Err(ProviderError::from(e).into()), None)),
+                };
+
+                let kind = discriminant(&status);
+                if last_status == Some(kind) {
+                    continue;
+                }
+
+                let next_state = match status {
+                    TransactionStatus::Submitted { .. } => Some(Some(kind)),
+                    _ => None,
+                };
+
+                return Some((Ok(status), next_state));
+            }
+        })
+        .boxed()
+    }
+
+    /// Returns a stream of the blocks produced from now on, in order of height, checked for
+    /// with the [`DEFAULT_POLLING_INTERVAL`]. See [`poll_new_blocks`](Self::poll_new_blocks).
+    pub async fn subscribe_new_blocks(&self) -> Result<BoxStream<'_, Result<Block, Error>>, Error> {
+        self.poll_new_blocks(DEFAULT_POLLING_INTERVAL).await
+    }
+
+    /// Returns a stream of the blocks produced from now on, in order of height, checked for
+    /// every `interval`. The node has no subscription to new blocks, hence the polling. The
+    /// stream ends after the first error.
+    pub async fn poll_new_blocks(
+        &self,
+        interval: Duration,
+    ) -> Result<BoxStream<'_, Result<Block, Error>>, Error> {
+        let next_height = self.latest_block_height().await? + 1;
+
+        Ok(stream::unfold(
+            Some((next_height, VecDeque::new())),
+            move |state| async move {
+                let (mut next_height, mut blocks) = state?;
+
+                while blocks.is_empty() {
+                    tokio::time::sleep(interval).await;
+
+                    match self.blocks_from(next_height).await {
+                        Ok(new_blocks) => blocks.extend(new_blocks),
+                        Err(e) => return Some((Err(e.into()), None)),
+                    }
+                    if let Some(block) = blocks.back() {
+                        next_height = block.header().height() + 1;
+                    }
+                }
+
+                let block = blocks.pop_front()?;
+                Some((Ok(block), Some((next_height, blocks))))
+            },
+        )
+        .boxed())
+    }
+
+    /// Returns the blocks from `height` up to the latest one, in order of height.
+    async fn blocks_from(&self, height: u64) -> Result<Vec<Block>, ProviderError> {
+        let mut latest_height = self.latest_block_height().await?;
+
+        loop {
+            if latest_height < height {
+                return Ok(vec![]);
+            }
+
+            let mut blocks = self
+                .get_blocks(PaginationRequest {
+                    cursor: None,
+                    results: (latest_height - height + 1) as usize,
+                    direction: PageDirection::Backward,
+                })
+                .await?
+                .results;
+            blocks.reverse();
+
+            // Blocks produced since `latest_height` was queried push the oldest ones out of the
+            // page, which is then queried again
+            match blocks.first() {
+                Some(block) if block.header().height() > height => {
+                    latest_height = blocks.last().expect("not empty").header().height();
+                }
+                _ => {
+                    blocks.retain(|block| block.header().height() >= height);
+                    return Ok(blocks);
+                }
+            }
+        }
+    }
+}
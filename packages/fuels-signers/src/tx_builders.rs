@@ -0,0 +1,441 @@
+use crate::{Signer, WalletUnlocked};
+use fuel_crypto::Signature;
+use fuel_gql_client::{
+    fuel_tx::{
+        Address, AssetId, Bytes32, Cacheable, ContractId, Input, Output, Salt, StorageSlot, Witness,
+    },
+    fuel_vm::{consts::REG_ONE, prelude::GTFArgs, prelude::Opcode},
+};
+use fuel_types::bytes::WORD_SIZE;
+use fuels_core::{
+    constants::{BASE_ASSET_ID, DEFAULT_GAS_PRICE},
+    parameters::TxParameters,
+    tx::{field, Chargeable, Create, Script, Transaction, UniqueIdentifier},
+};
+use fuels_types::errors::Error;
+use std::collections::HashSet;
+
+/// Accumulates the parts of a script transaction. The transaction is either
+/// built as is, or finalized: funded with the base asset of a wallet, which
+/// pays for the fee, and signed.
+#[derive(Debug, Clone, Default)]
+pub struct ScriptTransactionBuilder {
+    pub tx_params: TxParameters,
+    pub script: Vec<u8>,
+    pub script_data: Vec<u8>,
+    pub inputs: Vec<Input>,
+    pub outputs: Vec<Output>,
+    pub witnesses: Vec<Witness>,
+}
+
+impl ScriptTransactionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prepares a transaction moving the given inputs to the given outputs.
+    pub fn prepare_transfer(
+        inputs: Vec<Input>,
+        outputs: Vec<Output>,
+        params: TxParameters,
+    ) -> Self {
+        // This script contains a single Opcode that returns immediately (RET)
+        // since all this transaction does is move Inputs and Outputs around.
+        let script = Opcode::RET(REG_ONE).to_bytes().to_vec();
+
+        Self::new()
+            .set_tx_params(params)
+            .set_script(script)
+            .set_inputs(inputs)
+            .set_outputs(outputs)
+    }
+
+    /// Prepares a transaction transferring `amount` of `asset_id` to the contract `to`. The
+    /// inputs must include the contract's.
+    pub fn prepare_contract_transfer(
+        to: ContractId,
+        amount: u64,
+        asset_id: AssetId,
+        inputs: Vec<Input>,
+        outputs: Vec<Output>,
+        params: TxParameters,
+    ) -> Self {
+        let script_data: Vec<u8> = [
+            to.to_vec(),
+            amount.to_be_bytes().to_vec(),
+            asset_id.to_vec(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        // This script loads:
+        //  - a pointer to the contract id,
+        //  - the actual amount
+        //  - a pointer to the asset id
+        // into the registers 0x10, 0x12, 0x13
+        // and calls the TR instruction
+        let script = vec![
+            Opcode::gtf(0x10, 0x00, GTFArgs::ScriptData),
+            Opcode::ADDI(0x11, 0x10, ContractId::LEN as u16),
+            Opcode::LW(0x12, 0x11, 0),
+            Opcode::ADDI(0x13, 0x11, WORD_SIZE as u16),
+            Opcode::TR(0x10, 0x12, 0x13),
+            Opcode::RET(REG_ONE),
+        ]
+        .into_iter()
+        .collect();
+
+        Self::new()
+            .set_tx_params(params)
+            .set_script(script)
+            .set_script_data(script_data)
+            .set_inputs(inputs)
+            .set_outputs(outputs)
+    }
+
+    pub fn set_tx_params(mut self, tx_params: TxParameters) -> Self {
+        self.tx_params = tx_params;
+        self
+    }
+
+    pub fn set_script(mut self, script: Vec<u8>) -> Self {
+        self.script = script;
+        self
+    }
+
+    pub fn set_script_data(mut self, script_data: Vec<u8>) -> Self {
+        self.script_data = script_data;
+        self
+    }
+
+    pub fn set_inputs(mut self, inputs: Vec<Input>) -> Self {
+        self.inputs = inputs;
+        self
+    }
+
+    pub fn set_outputs(mut self, outputs: Vec<Output>) -> Self {
+        self.outputs = outputs;
+        self
+    }
+
+    pub fn set_witnesses(mut self, witnesses: Vec<Witness>) -> Self {
+        self.witnesses = witnesses;
+        self
+    }
+
+    /// Builds the transaction as is, without funding nor signing it. Without a provider to ask,
+    /// an unset gas price is built as [`DEFAULT_GAS_PRICE`].
+    pub fn build(self) -> Script {
+        Transaction::script(
+            self.tx_params.gas_price.unwrap_or(DEFAULT_GAS_PRICE),
+            self.tx_params.gas_limit,
+            self.tx_params.maturity,
+            self.script,
+            self.script_data,
+            self.inputs,
+            self.outputs,
+            self.witnesses,
+        )
+    }
+
+    /// Builds the transaction, funded and signed by `fee_payer`. See
+    /// [`finalize_with_signers`](Self::finalize_with_signers).
+    pub async fn finalize(
+        self,
+        fee_payer: &WalletUnlocked,
+        previous_base_amount: u64,
+    ) -> Result<Script, Error> {
+        self.finalize_with_signers::<WalletUnlocked>(fee_payer, previous_base_amount, &[])
+            .await
+    }
+
+    /// Builds the transaction and funds it with base asset inputs of `fee_payer`, covering the
+    /// fee on top of the `previous_base_amount` already spent by the transaction. Without a gas
+    /// price in the parameters, the one given by the gas price strategy of the provider of
+    /// `fee_payer` is used. The
+    /// transaction is then signed by `fee_payer` followed by `signers`: the witness index of the
+    /// fee payer is the number of witnesses set on the builder, the one of each signer follows.
+    ///
+    /// The base asset inputs of `fee_payer` are replaced by the ones funding the transaction,
+    /// the inputs owned by anyone else are kept. The base asset they spend must be paid back to
+    /// their owners with coin outputs, the base asset change going to `fee_payer`.
+    ///
+    /// If the transaction can't be finalized, the resources reserved for its inputs by the
+    /// resource tracker of `fee_payer`, if any, are released.
+    pub async fn finalize_with_signers<S>(
+        self,
+        fee_payer: &WalletUnlocked,
+        previous_base_amount: u64,
+        signers: &[S],
+    ) -> Result<Script, Error>
+    where
+        S: Signer,
+        Error: From<S::Error>,
+    {
+        let gas_price = self.tx_params.gas_price;
+
+        finalize(
+            self.build(),
+            gas_price,
+            fee_payer,
+            previous_base_amount,
+            signers,
+        )
+        .await
+    }
+}
+
+/// Accumulates the parts of a transaction deploying a contract, see
+/// [`ScriptTransactionBuilder`]. The bytecode of the contract is one of the witnesses.
+#[derive(Debug, Clone, Default)]
+pub struct CreateTransactionBuilder {
+    pub tx_params: TxParameters,
+    pub bytecode_witness_index: u8,
+    pub salt: Salt,
+    pub storage_slots: Vec<StorageSlot>,
+    pub inputs: Vec<Input>,
+    pub outputs: Vec<Output>,
+    pub witnesses: Vec<Witness>,
+}
+
+impl CreateTransactionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prepares the deployment of `bytecode`, set as the first witness, creating the contract
+    /// `contract_id` with the given state root.
+    pub fn prepare_contract_deployment(
+        bytecode: Vec<u8>,
+        contract_id: ContractId,
+        state_root: Bytes32,
+        salt: Salt,
+        storage_slots: Vec<StorageSlot>,
+        params: TxParameters,
+    ) -> Self {
+        Self::new()
+            .set_tx_params(params)
+            .set_bytecode_witness_index(0)
+            .set_salt(salt)
+            .set_storage_slots(storage_slots)
+            .set_outputs(vec![Output::contract_created(contract_id, state_root)])
+            .set_witnesses(vec![bytecode.into()])
+    }
+
+    pub fn set_tx_params(mut self, tx_params: TxParameters) -> Self {
+        self.tx_params = tx_params;
+        self
+    }
+
+    pub fn set_bytecode_witness_index(mut self, bytecode_witness_index: u8) -> Self {
+        self.bytecode_witness_index = bytecode_witness_index;
+        self
+    }
+
+    pub fn set_salt(mut self, salt: impl Into<Salt>) -> Self {
+        self.salt = salt.into();
+        self
+    }
+
+    pub fn set_storage_slots(mut self, storage_slots: Vec<StorageSlot>) -> Self {
+        self.storage_slots = storage_slots;
+        self
+    }
+
+    pub fn set_inputs(mut self, inputs: Vec<Input>) -> Self {
+        self.inputs = inputs;
+        self
+    }
+
+    pub fn set_outputs(mut self, outputs: Vec<Output>) -> Self {
+        self.outputs = outputs;
+        self
+    }
+
+    pub fn set_witnesses(mut self, witnesses: Vec<Witness>) -> Self {
+        self.witnesses = witnesses;
+        self
+    }
+
+    /// Builds the transaction as is, without funding nor signing it. Without a provider to ask,
+    /// an unset gas price is built as [`DEFAULT_GAS_PRICE`].
+    pub fn build(self) -> Create {
+        Transaction::create(
+            self.tx_params.gas_price.unwrap_or(DEFAULT_GAS_PRICE),
+            self.tx_params.gas_limit,
+            self.tx_params.maturity,
+            self.bytecode_witness_index,
+            self.salt,
+            self.storage_slots,
+            self.inputs,
+            self.outputs,
+            self.witnesses,
+        )
+    }
+
+    /// Builds the transaction, funded and signed by `fee_payer`. See
+    /// [`ScriptTransactionBuilder::finalize_with_signers`].
+    pub async fn finalize(
+        self,
+        fee_payer: &WalletUnlocked,
+        previous_base_amount: u64,
+    ) -> Result<Create, Error> {
+        self.finalize_with_signers::<WalletUnlocked>(fee_payer, previous_base_amount, &[])
+            .await
+    }
+
+    /// Builds the transaction, funded by `fee_payer` and signed by it and `signers`. See
+    /// [`ScriptTransactionBuilder::finalize_with_signers`].
+    pub async fn finalize_with_signers<S>(
+        self,
+        fee_payer: &WalletUnlocked,
+        previous_base_amount: u64,
+        signers: &[S],
+    ) -> Result<Create, Error>
+    where
+        S: Signer,
+        Error: From<S::Error>,
+    {
+        let gas_price = self.tx_params.gas_price;
+
+        finalize(
+            self.build(),
+            gas_price,
+            fee_payer,
+            previous_base_amount,
+            signers,
+        )
+        .await
+    }
+}
+
+async fn finalize<Tx, S>(
+    mut tx: Tx,
+    gas_price: Option<u64>,
+    fee_payer: &WalletUnlocked,
+    previous_base_amount: u64,
+    signers: &[S],
+) -> Result<Tx, Error>
+where
+    Tx: Chargeable
+        + field::GasPrice
+        + field::Inputs
+        + field::Outputs
+        + field::Witnesses
+        + Cacheable
+        + UniqueIdentifier
+        + Send,
+    S: Signer,
+    Error: From<S::Error>,
+{
+    let result = fund_and_sign(&mut tx, gas_price, fee_payer, previous_base_amount, signers).await;
+    // A transaction that is never sent mustn't keep its resources reserved
+    if result.is_err() {
+        fee_payer.release_resources(tx.inputs()).await;
+    }
+
+    result.map(|_| tx)
+}
+
+async fn fund_and_sign<Tx, S>(
+    tx: &mut Tx,
+    gas_price: Option<u64>,
+    fee_payer: &WalletUnlocked,
+    previous_base_amount: u64,
+    signers: &[S],
+) -> Result<(), Error>
+where
+    Tx: Chargeable
+        + field::GasPrice
+        + field::Inputs
+        + field::Outputs
+        + field::Witnesses
+        + Cacheable
+        + UniqueIdentifier
+        + Send,
+    S: Signer,
+    Error: From<S::Error>,
+{
+    if gas_price.is_none() {
+        *tx.gas_price_mut() = fee_payer.get_provider()?.gas_price().await?;
+    }
+
+    let fee_payer_address = Address::from(fee_payer.address());
+    check_base_asset_paid_back(tx, &fee_payer_address)?;
+
+    // The fee covers the witnesses of the fee payer and the signers, set once signed: it is
+    // computed with placeholders of the same size
+    let num_witnesses = tx.witnesses().len();
+    let fee_payer_witness_index = num_witnesses as u8;
+    let placeholder = Witness::from(vec![0u8; Signature::LEN]);
+    tx.witnesses_mut()
+        .extend(vec![placeholder; signers.len() + 1]);
+
+    let result = fee_payer
+        .add_fee_coins(tx, previous_base_amount, fee_payer_witness_index)
+        .await;
+    tx.witnesses_mut().truncate(num_witnesses);
+    result?;
+
+    fee_payer.sign_transaction(tx).await?;
+    for signer in signers {
+        signer.sign_transaction(tx).await?;
+    }
+
+    Ok(())
+}
+
+fn is_owned_by(input: &Input, address: &Address) -> bool {
+    match input {
+        Input::MessageSigned { recipient, .. } => recipient == address,
+        _ => input.input_owner() == Some(address),
+    }
+}
+
+/// Checks that the base asset spent by owners other than the fee payer is paid back to them
+/// with coin outputs: `add_fee_coins` keeps their inputs, and whatever isn't paid back to them
+/// would end up in the change of the fee payer. For the same reason, the base asset change must
+/// go to the fee payer.
+fn check_base_asset_paid_back<Tx: field::Inputs + field::Outputs>(
+    tx: &Tx,
+    fee_payer_address: &Address,
+) -> Result<(), Error> {
+    let other_owners: HashSet<Address> = tx
+        .inputs()
+        .iter()
+        .filter(|input| {
+            matches!(input, Input::MessageSigned { .. })
+                || matches!(input, Input::CoinSigned { asset_id, .. } if *asset_id == BASE_ASSET_ID)
+        })
+        .filter(|input| !is_owned_by(input, fee_payer_address))
+        .filter_map(|input| match input {
+            Input::MessageSigned { recipient, .. } => Some(*recipient),
+            _ => input.input_owner().copied(),
+        })
+        .collect();
+
+    for output in tx.outputs() {
+        if let Output::Change { to, asset_id, .. } = output {
+            if *asset_id == BASE_ASSET_ID && to != fee_payer_address {
+                return Err(Error::WalletError(format!(
+                    "the base asset change must go to the fee payer {fee_payer_address}, not to {to}"
+                )));
+            }
+        }
+    }
+
+    for owner in other_owners {
+        let is_paid_back = tx.outputs().iter().any(|output| {
+            matches!(output, Output::Coin { to, asset_id, .. } if *to == owner && *asset_id == BASE_ASSET_ID)
+        });
+        if !is_paid_back {
+            return Err(Error::WalletError(format!(
+                "the base asset inputs of {owner} must be paid back to it with a coin output, \
+                otherwise the fee payer would get them as change"
+            )));
+        }
+    }
+
+    Ok(())
+}
@@ -1,4 +1,8 @@
+use crate::coin_selection::CoinSelector;
+use crate::pending_transaction::PendingTransaction;
 use crate::provider::Provider;
+use crate::resource_tracker::ResourceTracker;
+use crate::tx_builders::ScriptTransactionBuilder;
 use crate::Signer;
 use async_trait::async_trait;
 use elliptic_curve::rand_core;
@@ -7,25 +11,24 @@ use fuel_crypto::{Message, PublicKey, SecretKey, Signature};
 use fuel_gql_client::client::schema;
 use fuel_gql_client::client::schema::resource::Resource;
 use fuel_gql_client::client::types::TransactionResponse;
-use fuel_gql_client::fuel_vm::prelude::GTFArgs;
 use fuel_gql_client::{
     client::{
-        schema::coin::Coin, schema::message::Message as InputMessage, PaginatedResult,
-        PaginationRequest,
+        schema::coin::{Coin, CoinStatus},
+        schema::message::Message as InputMessage,
+        PaginatedResult, PaginationRequest,
     },
     fuel_tx::{
-        AssetId, Bytes32, Cacheable, ContractId, Input, Output, Receipt, TransactionFee, TxPointer,
-        UtxoId, Witness,
+        Address, AssetId, Bytes32, Cacheable, ContractId, Input, Output, Receipt, TransactionFee,
+        TxPointer, UtxoId, Witness,
     },
-    fuel_vm::{consts::REG_ONE, prelude::Opcode},
+    fuel_types::MessageId,
 };
-use fuel_types::bytes::WORD_SIZE;
-use fuels_core::tx::{field, Chargeable, Script, Transaction, UniqueIdentifier};
+use fuels_core::tx::{field, field::Inputs, Chargeable, Script, UniqueIdentifier};
 use fuels_core::{constants::BASE_ASSET_ID, parameters::TxParameters};
 use fuels_types::bech32::{Bech32Address, Bech32ContractId, FUEL_BECH32_HRP};
 use fuels_types::errors::Error;
 use rand::{CryptoRng, Rng};
-use std::{collections::HashMap, fmt, ops, path::Path};
+use std::{collections::HashMap, fmt, ops, path::Path, sync::Arc};
 use thiserror::Error;
 
 pub const DEFAULT_DERIVATION_PATH_PREFIX: &str = "m/44'/1179993420'";
@@ -43,6 +46,8 @@ pub struct Wallet {
     /// from the first 32 bytes of SHA-256 hash of the wallet's public key.
     pub(crate) address: Bech32Address,
     pub(crate) provider: Option<Provider>,
+    pub(crate) resource_tracker: Option<ResourceTracker>,
+    pub(crate) coin_selector: Option<Arc<dyn CoinSelector>>,
 }
 
 /// A `WalletUnlocked` is equivalent to a [`Wallet`] whose private key is known and stored
@@ -116,7 +121,12 @@ impl From<WalletError> for Error {
 impl Wallet {
     /// Construct a Wallet from its given public address.
     pub fn from_address(address: Bech32Address, provider: Option<Provider>) -> Self {
-        Self { address, provider }
+        Self {
+            address,
+            provider,
+            resource_tracker: None,
+            coin_selector: None,
+        }
     }
 
     pub fn get_provider(&self) -> Result<&Provider, WalletError> {
@@ -131,6 +141,51 @@ impl Wallet {
         &self.address
     }
 
+    /// Makes the wallet reserve the resources it selects for its transactions with `tracker`,
+    /// so that it can send concurrent transactions. See [`ResourceTracker`].
+    pub fn set_resource_tracker(&mut self, tracker: ResourceTracker) {
+        self.resource_tracker = Some(tracker)
+    }
+
+    pub fn get_resource_tracker(&self) -> Option<&ResourceTracker> {
+        self.resource_tracker.as_ref()
+    }
+
+    /// Makes the wallet select the coins it spends with `selector`, among its spendable coins,
+    /// instead of letting the node select its resources. Note that messages are then never
+    /// spent.
+    pub fn set_coin_selector(&mut self, selector: impl CoinSelector + 'static) {
+        self.coin_selector = Some(Arc::new(selector))
+    }
+
+    /// Releases the resources spent by `inputs` if the wallet tracks its resources, to be
+    /// called once their transaction is committed, or won't be.
+    pub async fn release_resources(&self, inputs: &[Input]) {
+        if let Some(tracker) = &self.resource_tracker {
+            tracker.release(inputs).await;
+        }
+    }
+
+    /// Ties the resources spent by `inputs` to the outcome of the submission of their
+    /// transaction, if the wallet tracks its resources: they are released right away if the
+    /// submission failed, otherwise once the returned [`PendingTransaction`] is committed.
+    pub async fn track_submission(
+        &self,
+        submission: Result<PendingTransaction, Error>,
+        inputs: &[Input],
+    ) -> Result<PendingTransaction, Error> {
+        match (submission, &self.resource_tracker) {
+            (Ok(pending_transaction), Some(tracker)) => {
+                Ok(pending_transaction.with_reserved_resources(tracker.clone(), inputs.to_vec()))
+            }
+            (Err(e), Some(tracker)) => {
+                tracker.release(inputs).await;
+                Err(e)
+            }
+            (submission, None) => submission,
+        }
+    }
+
     pub async fn get_transactions(
         &self,
         request: PaginationRequest<String>,
@@ -153,7 +208,7 @@ impl Wallet {
         amount: u64,
         witness_index: u8,
     ) -> Result<Vec<Input>, Error> {
-        let spendable = self.get_spendable_resources(asset_id, amount).await?;
+        let spendable = self.reserve_spendable_resources(asset_id, amount).await?;
         let mut inputs = vec![];
         for resource in spendable {
             let input = match resource {
@@ -235,6 +290,109 @@ impl Wallet {
             .map_err(Into::into)
     }
 
+    /// Same as [`get_spendable_resources`](Self::get_spendable_resources), for resources about
+    /// to be spent: if the wallet tracks its resources, the reserved ones are left out and the
+    /// returned ones are reserved.
+    pub async fn reserve_spendable_resources(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<Resource>, Error> {
+        match &self.resource_tracker {
+            Some(tracker) => {
+                tracker
+                    .reserve(|excluded_utxo_ids, excluded_message_ids| async move {
+                        self.select_spendable_resources(
+                            asset_id,
+                            amount,
+                            &excluded_utxo_ids,
+                            &excluded_message_ids,
+                        )
+                        .await
+                    })
+                    .await
+            }
+            None => {
+                self.select_spendable_resources(asset_id, amount, &[], &[])
+                    .await
+            }
+        }
+    }
+
+    /// Selects spendable resources adding up at least to `amount` of `asset_id`, without the
+    /// excluded ones, with the coin selector of the wallet if it has one, otherwise by the node.
+    async fn select_spendable_resources(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_utxo_ids: &[UtxoId],
+        excluded_message_ids: &[MessageId],
+    ) -> Result<Vec<Resource>, Error> {
+        let provider = self.get_provider()?;
+
+        match &self.coin_selector {
+            Some(selector) => {
+                let coins = self.spendable_coins(asset_id, excluded_utxo_ids).await?;
+
+                let selection = selector.select(coins, amount).ok_or_else(|| {
+                    Error::WalletError(format!(
+                        "the coin selector couldn't select coins adding up to {amount} of asset {asset_id}"
+                    ))
+                })?;
+
+                Ok(selection.into_iter().map(Resource::Coin).collect())
+            }
+            None => Ok(provider
+                .get_spendable_resources_excluding(
+                    &self.address,
+                    asset_id,
+                    amount,
+                    excluded_utxo_ids,
+                    excluded_message_ids,
+                )
+                .await?),
+        }
+    }
+
+    /// Gets all the unspent coins of `asset_id` owned by the wallet, without the excluded ones.
+    async fn spendable_coins(
+        &self,
+        asset_id: AssetId,
+        excluded_utxo_ids: &[UtxoId],
+    ) -> Result<Vec<Coin>, Error> {
+        Ok(self
+            .get_coins(asset_id)
+            .await?
+            .into_iter()
+            .filter(|coin| {
+                coin.status == CoinStatus::Unspent
+                    && !excluded_utxo_ids.contains(&coin.utxo_id.clone().into())
+            })
+            .collect())
+    }
+
+    /// Returns inputs spending all the spendable coins of `asset_id` owned by the wallet,
+    /// reserved if the wallet tracks its resources.
+    async fn reserve_all_coin_inputs(&self, asset_id: AssetId) -> Result<Vec<Input>, Error> {
+        let select_all = |excluded_utxo_ids: Vec<UtxoId>, _: Vec<MessageId>| async move {
+            let coins = self.spendable_coins(asset_id, &excluded_utxo_ids).await?;
+
+            Ok::<_, Error>(coins.into_iter().map(Resource::Coin).collect())
+        };
+        let resources = match &self.resource_tracker {
+            Some(tracker) => tracker.reserve(select_all).await?,
+            None => select_all(vec![], vec![]).await?,
+        };
+
+        Ok(resources
+            .into_iter()
+            .filter_map(|resource| match resource {
+                Resource::Coin(coin) => Some(self.create_coin_input(coin, asset_id, 0)),
+                Resource::Message(_) => None,
+            })
+            .collect())
+    }
+
     /// Get the balance of all spendable coins `asset_id` for address `address`. This is different
     /// from getting coins because we are just returning a number (the sum of UTXOs amount) instead
     /// of the UTXOs.
@@ -272,19 +430,8 @@ impl Wallet {
 
     /// Craft a transaction used to transfer funds between two addresses.
     pub fn build_transfer_tx(inputs: &[Input], outputs: &[Output], params: TxParameters) -> Script {
-        // This script contains a single Opcode that returns immediately (RET)
-        // since all this transaction does is move Inputs and Outputs around.
-        let script = Opcode::RET(REG_ONE).to_bytes().to_vec();
-        Transaction::script(
-            params.gas_price,
-            params.gas_limit,
-            params.maturity,
-            script,
-            vec![],
-            inputs.to_vec(),
-            outputs.to_vec(),
-            vec![],
-        )
+        ScriptTransactionBuilder::prepare_transfer(inputs.to_vec(), outputs.to_vec(), params)
+            .build()
     }
 
     /// Craft a transaction used to transfer funds to a contract.
@@ -296,42 +443,15 @@ impl Wallet {
         outputs: &[Output],
         params: TxParameters,
     ) -> Script {
-        let script_data: Vec<u8> = [
-            to.to_vec(),
-            amount.to_be_bytes().to_vec(),
-            asset_id.to_vec(),
-        ]
-        .into_iter()
-        .flatten()
-        .collect();
-
-        // This script loads:
-        //  - a pointer to the contract id,
-        //  - the actual amount
-        //  - a pointer to the asset id
-        // into the registers 0x10, 0x12, 0x13
-        // and calls the TR instruction
-        let script = vec![
-            Opcode::gtf(0x10, 0x00, GTFArgs::ScriptData),
-            Opcode::ADDI(0x11, 0x10, ContractId::LEN as u16),
-            Opcode::LW(0x12, 0x11, 0),
-            Opcode::ADDI(0x13, 0x11, WORD_SIZE as u16),
-            Opcode::TR(0x10, 0x12, 0x13),
-            Opcode::RET(REG_ONE),
-        ]
-        .into_iter()
-        .collect();
-
-        Transaction::script(
-            params.gas_price,
-            params.gas_limit,
-            params.maturity,
-            script,
-            script_data,
+        ScriptTransactionBuilder::prepare_contract_transfer(
+            to,
+            amount,
+            asset_id,
             inputs.to_vec(),
             outputs.to_vec(),
-            vec![],
+            params,
         )
+        .build()
     }
 }
 
@@ -349,6 +469,14 @@ impl WalletUnlocked {
         self.wallet.set_provider(provider)
     }
 
+    pub fn set_resource_tracker(&mut self, tracker: ResourceTracker) {
+        self.wallet.set_resource_tracker(tracker)
+    }
+
+    pub fn set_coin_selector(&mut self, selector: impl CoinSelector + 'static) {
+        self.wallet.set_coin_selector(selector)
+    }
+
     /// Creates a new wallet with a random private key.
     pub fn new_random(provider: Option<Provider>) -> Self {
         let mut rng = rand::thread_rng();
@@ -444,6 +572,9 @@ impl WalletUnlocked {
     /// the existing transaction inputs because the selected resources may exceed
     /// the required amount to avoid dust. Therefore we require it as an argument.
     ///
+    /// The base asset inputs of the wallet are replaced by the ones covering the fee, the base
+    /// asset inputs of other owners are kept.
+    ///
     /// Requires contract inputs to be at the start of the transactions inputs vec
     /// so that their indexes are retained
     pub async fn add_fee_coins<Tx: Chargeable + field::Inputs + field::Outputs>(
@@ -460,10 +591,15 @@ impl WalletUnlocked {
         let transaction_fee = TransactionFee::checked_from_tx(&consensus_parameters.into(), tx)
             .expect("Error calculating TransactionFee");
 
+        // Only the base asset inputs of the wallet are replaced, the ones of other owners are kept
+        let address = Address::from(self.address());
         let (base_asset_inputs, remaining_inputs): (Vec<_>, Vec<_>) =
-            tx.inputs().iter().cloned().partition(|input| {
-                matches!(input, Input::MessageSigned { .. })
-                || matches!(input, Input::CoinSigned { asset_id, .. } if asset_id == &BASE_ASSET_ID)
+            tx.inputs().iter().cloned().partition(|input| match input {
+                Input::MessageSigned { recipient, .. } => recipient == &address,
+                Input::CoinSigned {
+                    asset_id, owner, ..
+                } => asset_id == &BASE_ASSET_ID && owner == &address,
+                _ => false,
             });
 
         let base_inputs_sum: u64 = base_asset_inputs
@@ -491,6 +627,10 @@ impl WalletUnlocked {
             new_base_amount = MIN_AMOUNT;
         }
 
+        // The dropped base asset inputs are selected again if needed. They are removed from the
+        // transaction first, so that its inputs stay the ones reserved for it, even on failure
+        self.release_resources(&base_asset_inputs).await;
+        *tx.inputs_mut() = remaining_inputs;
         let new_base_inputs = self
             .get_asset_inputs_for_amount(BASE_ASSET_ID, new_base_amount, witness_index)
             .await?;
@@ -499,12 +639,7 @@ impl WalletUnlocked {
                 "Response errors; enough resources could not be found".to_string(),
             ));
         }
-
-        let adjusted_inputs: Vec<_> = remaining_inputs
-            .into_iter()
-            .chain(new_base_inputs.into_iter())
-            .collect();
-        *tx.inputs_mut() = adjusted_inputs;
+        tx.inputs_mut().extend(new_base_inputs);
 
         let is_base_change_present = tx.outputs().iter().any(|output| {
             matches!(output, Output::Change { asset_id, .. } if asset_id == &BASE_ASSET_ID)
@@ -573,17 +708,130 @@ impl WalletUnlocked {
             .await?;
         let outputs = self.get_asset_outputs_for_amount(to, asset_id, amount);
 
-        let mut tx = Wallet::build_transfer_tx(&inputs, &outputs, tx_parameters);
-
         // if we are not transferring the base asset, previous base amount is 0
-        if asset_id == AssetId::default() {
-            self.add_fee_coins(&mut tx, amount, 0).await?;
+        let base_amount = if asset_id == AssetId::default() {
+            amount
         } else {
-            self.add_fee_coins(&mut tx, 0, 0).await?;
+            0
         };
-        self.sign_transaction(&mut tx).await?;
+        let tx = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_parameters)
+            .finalize(self, base_amount)
+            .await?;
 
-        let receipts = self.get_provider()?.send_transaction(&tx).await?;
+        let receipts = self.send_transaction(&tx).await?;
+
+        Ok((tx.id().to_string(), receipts))
+    }
+
+    /// Merges the spendable coins of `asset_id` owned by the wallet into a single coin, with
+    /// transactions spending at most `max_inputs_per_tx` of them each, not counting the base
+    /// asset inputs paying the fees. As many transactions as needed are sent, the coins they
+    /// produce being merged in turn. Base asset coins pay the fee of their own merge.
+    /// Returns the transaction IDs and the lists of receipts of the transactions sent.
+    pub async fn consolidate_coins(
+        &self,
+        asset_id: AssetId,
+        max_inputs_per_tx: usize,
+        tx_parameters: TxParameters,
+    ) -> Result<Vec<(String, Vec<Receipt>)>, Error> {
+        if max_inputs_per_tx < 2 {
+            return Err(Error::WalletError(format!(
+                "can't merge coins with at most {max_inputs_per_tx} input per transaction"
+            )));
+        }
+        let tx_parameters = self
+            .get_provider()?
+            .apply_gas_price_strategy(tx_parameters)
+            .await?;
+
+        let mut sent_txs = vec![];
+        loop {
+            let inputs = self.reserve_all_coin_inputs(asset_id).await?;
+            if inputs.len() <= 1 {
+                self.release_resources(&inputs).await;
+                return Ok(sent_txs);
+            }
+
+            let result = self
+                .merge_coin_inputs(&inputs, asset_id, max_inputs_per_tx, tx_parameters)
+                .await;
+            // The inputs that weren't spent, e.g. after a failed transaction, are released too
+            self.release_resources(&inputs).await;
+            sent_txs.extend(result?);
+        }
+    }
+
+    /// Merges `inputs` in chunks of `max_inputs_per_tx`, see
+    /// [`consolidate_coins`](Self::consolidate_coins).
+    async fn merge_coin_inputs(
+        &self,
+        inputs: &[Input],
+        asset_id: AssetId,
+        max_inputs_per_tx: usize,
+        tx_parameters: TxParameters,
+    ) -> Result<Vec<(String, Vec<Receipt>)>, Error> {
+        let mut sent_txs = vec![];
+        for chunk in inputs.chunks(max_inputs_per_tx) {
+            if chunk.len() < 2 {
+                continue;
+            }
+
+            let outputs = vec![Output::change((&self.address).into(), 0, asset_id)];
+            let tx_builder =
+                ScriptTransactionBuilder::prepare_transfer(chunk.to_vec(), outputs, tx_parameters);
+            // `add_fee_coins` would select base asset coins again, instead of the merged ones
+            let tx = if asset_id == BASE_ASSET_ID {
+                let mut tx = tx_builder.build();
+                self.sign_transaction(&mut tx).await?;
+                tx
+            } else {
+                tx_builder.finalize(self, 0).await?
+            };
+
+            let receipts = self.send_transaction(&tx).await?;
+            sent_txs.push((tx.id().to_string(), receipts));
+        }
+
+        Ok(sent_txs)
+    }
+
+    /// Splits `n` coins of `amount_each` of `asset_id` off the spendable coins of the wallet,
+    /// in a single transaction. Returns the transaction ID and the list of receipts.
+    pub async fn split_coins(
+        &self,
+        asset_id: AssetId,
+        n: usize,
+        amount_each: u64,
+        tx_parameters: TxParameters,
+    ) -> Result<(String, Vec<Receipt>), Error> {
+        if n == 0 || amount_each == 0 {
+            return Err(Error::WalletError(format!(
+                "can't split {n} coins of {amount_each}: both must be greater than 0"
+            )));
+        }
+        let amount = (n as u64).checked_mul(amount_each).ok_or_else(|| {
+            Error::WalletError(format!(
+                "can't split {n} coins of {amount_each}: the total amount overflows"
+            ))
+        })?;
+
+        let inputs = self
+            .get_asset_inputs_for_amount(asset_id, amount, 0)
+            .await?;
+        let mut outputs = vec![Output::coin((&self.address).into(), amount_each, asset_id); n];
+        outputs.push(Output::change((&self.address).into(), 0, asset_id));
+
+        // if we are not splitting the base asset, previous base amount is 0
+        let base_amount = if asset_id == AssetId::default() {
+            amount
+        } else {
+            0
+        };
+        let tx = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_parameters)
+            .finalize(self, base_amount)
+            .await?;
+
+        let receipts = self.send_transaction(&tx).await?;
 
         Ok((tx.id().to_string(), receipts))
     }
@@ -624,17 +872,17 @@ impl WalletUnlocked {
             })
             .collect::<Vec<_>>();
 
-        let outputs = [
+        let outputs = vec![
             Output::coin(to.into(), amount, asset_id),
             Output::coin(predicate_address.into(), input_amount - amount, asset_id),
         ];
 
-        let mut tx = Wallet::build_transfer_tx(&inputs, &outputs, tx_parameters);
         // we set previous base amount to 0 because it only applies to signed coins, not predicate coins
-        self.add_fee_coins(&mut tx, 0, 0).await?;
-        self.sign_transaction(&mut tx).await?;
+        let tx = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_parameters)
+            .finalize(self, 0)
+            .await?;
 
-        self.get_provider()?.send_transaction(&tx).await
+        self.send_transaction(&tx).await
     }
 
     fn create_coin_predicate(
@@ -738,29 +986,37 @@ impl WalletUnlocked {
             Output::change((&self.address).into(), 0, asset_id),
         ];
 
-        // Build transaction and sign it
-        let mut tx = Wallet::build_contract_transfer_tx(
-            plain_contract_id,
-            balance,
-            asset_id,
-            &inputs,
-            &outputs,
-            tx_parameters,
-        );
         // if we are not transferring the base asset, previous base amount is 0
         let base_amount = if asset_id == AssetId::default() {
             balance
         } else {
             0
         };
-        self.add_fee_coins(&mut tx, base_amount, 0).await?;
-        self.sign_transaction(&mut tx).await?;
+        // Build transaction and sign it
+        let tx = ScriptTransactionBuilder::prepare_contract_transfer(
+            plain_contract_id,
+            balance,
+            asset_id,
+            inputs,
+            outputs,
+            tx_parameters,
+        )
+        .finalize(self, base_amount)
+        .await?;
 
         let tx_id = tx.id();
-        let receipts = self.get_provider()?.send_transaction(&tx).await?;
+        let receipts = self.send_transaction(&tx).await?;
 
         Ok((tx_id.to_string(), receipts))
     }
+
+    /// Sends the transaction, then releases the resources it spends if the wallet tracks them.
+    async fn send_transaction(&self, tx: &Script) -> Result<Vec<Receipt>, Error> {
+        let result = self.get_provider()?.send_transaction(tx).await;
+        self.release_resources(tx.inputs()).await;
+
+        result
+    }
 }
 
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
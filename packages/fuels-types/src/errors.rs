@@ -55,6 +55,8 @@ pub enum Error {
     ValidationError(#[from] CheckError),
     #[error("Revert transaction error: {}, receipts: {:?}", .0, .1)]
     RevertTransactionError(String, Vec<Receipt>),
+    #[error("Mock error: {0}")]
+    MockError(String),
 }
 
 impl From<CodecError> for Error {
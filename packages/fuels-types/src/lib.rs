@@ -33,6 +33,7 @@ pub struct ProgramABI {
     pub types: Vec<TypeDeclaration>,
     pub functions: Vec<ABIFunction>,
     pub logged_types: Option<Vec<LoggedType>>,
+    pub configurables: Option<Vec<Configurable>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -70,6 +71,17 @@ pub struct LoggedType {
     pub application: TypeApplication,
 }
 
+/// A constant of the program whose value can be changed by patching the
+/// binary at `offset`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Configurable {
+    pub name: String,
+    #[serde(rename = "configurableType")]
+    pub application: TypeApplication,
+    pub offset: u64,
+}
+
 #[derive(Debug, Clone)]
 pub struct ResolvedLog {
     pub log_id: u64,
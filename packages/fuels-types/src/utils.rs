@@ -53,13 +53,28 @@ pub fn has_enum_format(field: &str) -> bool {
 }
 
 // A custom type name should be passed to this function as `{struct,enum} $name`,
+// where `$name` may be qualified with the module path of the type (e.g.
+// `struct lib_a::Error`). Only the name of the type is returned, without the
+// module path.
 pub fn custom_type_name(type_field: &str) -> Result<String, Error> {
+    custom_type_path(type_field).map(|mut path| path.pop().expect("path cannot be empty"))
+}
+
+// Returns the segments of the, possibly module qualified, path of the custom
+// type given as `{struct,enum} $path` -- i.e. for `struct lib_a::Error` it
+// returns ['lib_a', 'Error'].
+pub fn custom_type_path(type_field: &str) -> Result<Vec<String>, Error> {
     lazy_static! {
         static ref RE: Regex = Regex::new(r"(?:struct|enum)\s*(.*)").unwrap();
     }
 
     RE.captures(type_field)
-        .map(|captures| String::from(&captures[1]))
+        .map(|captures| {
+            captures[1]
+                .split("::")
+                .map(|segment| segment.trim().to_string())
+                .collect()
+        })
         .ok_or_else(|| {
             Error::InvalidData(
                 "The declared type was not in the format `(enum|struct) name`".to_string(),
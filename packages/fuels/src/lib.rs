@@ -63,7 +63,9 @@ pub mod prelude {
     //! ```
 
     pub use super::contract::{
+        call_response::SubmittedCall,
         contract::{Contract, MultiContractCallHandler},
+        events::{ContractEvent, ContractEventStream, EventCursor},
         logs::LogDecoder,
         predicate::Predicate,
     };
@@ -74,8 +76,19 @@ pub mod prelude {
     pub use super::core::Identity;
     pub use super::core::{Token, Tokenizable};
     pub use super::fuel_node::*;
-    pub use super::fuels_abigen::{abigen, script_abigen, setup_contract_test};
+    pub use super::fuels_abigen::{abigen, predicate_abigen, script_abigen, setup_contract_test};
+    pub use super::signers::coin_selection::{
+        CoinSelector, ExactMatchPreferred, LargestFirst, MaxInputs, SmallestFirst,
+    };
+    pub use super::signers::gas_price::{
+        FixedGasPrice, GasPriceStrategy, MinGasPriceMultiplier, NodeMinGasPrice,
+        RecentBlocksPercentile,
+    };
+    pub use super::signers::pending_transaction::PendingTransaction;
     pub use super::signers::provider::*;
+    pub use super::signers::resource_tracker::ResourceTracker;
+    pub use super::signers::subscriptions::DEFAULT_POLLING_INTERVAL;
+    pub use super::signers::tx_builders::{CreateTransactionBuilder, ScriptTransactionBuilder};
     pub use super::signers::{wallet::generate_mnemonic_phrase, Signer, Wallet, WalletUnlocked};
     pub use super::test_helpers::Config;
     pub use super::test_helpers::*;
@@ -1,7 +1,7 @@
 use fuels::core::abi_encoder::ABIEncoder;
 use fuels::prelude::*;
 use sha2::{Digest, Sha256};
-use std::{slice, str::FromStr};
+use std::{collections::HashSet, slice, str::FromStr};
 
 pub fn null_contract_id() -> Bech32ContractId {
     // a bech32 contract address that decodes to [0u8;32]
@@ -801,3 +801,558 @@ async fn compile_bindings_enum_input() {
     let expected = "0000000021b2784f0000000000000000000000000000002a";
     assert_eq!(encoded, expected);
 }
+
+#[tokio::test]
+async fn compile_bindings_unit_enum_input() -> Result<(), Error> {
+    // Enums whose variants are all `()` are generated as C-like Rust enums.
+    abigen!(
+        SimpleContract,
+        r#"
+        {
+            "types": [
+              {
+                "typeId": 0,
+                "type": "()",
+                "components": [],
+                "typeParameters": null
+              },
+              {
+                "typeId": 1,
+                "type": "enum Color",
+                "components": [
+                  {
+                    "name": "Red",
+                    "type": 0,
+                    "typeArguments": null
+                  },
+                  {
+                    "name": "Green",
+                    "type": 0,
+                    "typeArguments": null
+                  },
+                  {
+                    "name": "Blue",
+                    "type": 0,
+                    "typeArguments": null
+                  }
+                ],
+                "typeParameters": null
+              }
+            ],
+            "functions": [
+              {
+                "inputs": [
+                  {
+                    "name": "color",
+                    "type": 1,
+                    "typeArguments": null
+                  }
+                ],
+                "name": "takes_color",
+                "output": {
+                  "name": "",
+                  "type": 0,
+                  "typeArguments": null
+                }
+              }
+            ]
+          }
+        "#,
+    );
+
+    assert_eq!(Color::Blue as u8, 2);
+    assert_eq!(u64::from(Color::Green), 1);
+    assert_eq!(Color::try_from(0u64)?, Color::Red);
+    assert!(Color::try_from(3u64).is_err());
+
+    let colors = HashSet::from([Color::Red, Color::Green, Color::Red]);
+    assert_eq!(colors.len(), 2);
+
+    let wallet = launch_provider_and_get_wallet().await;
+
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+
+    let call_handler = contract_instance.methods().takes_color(Color::Green);
+
+    let encoded_args = call_handler.contract_call.encoded_args.resolve(0);
+    assert_eq!(hex::encode(encoded_args), "0000000000000001");
+    Ok(())
+}
+
+// Types shared between the bindings of several contracts. Used by the test below
+// to replace the code that would otherwise be generated for `InnerStruct`.
+mod shared_types {
+    use fuels::prelude::*;
+
+    abigen!(
+        SharedTypes,
+        r#"
+        {
+            "types": [
+              {
+                "typeId": 0,
+                "type": "bool",
+                "components": null,
+                "typeParameters": null
+              },
+              {
+                "typeId": 1,
+                "type": "struct InnerStruct",
+                "components": [
+                  {
+                    "name": "a",
+                    "type": 0,
+                    "typeArguments": null
+                  }
+                ],
+                "typeParameters": null
+              }
+            ],
+            "functions": []
+          }
+        "#,
+    );
+}
+
+#[tokio::test]
+async fn compile_bindings_with_custom_type_paths() {
+    // `InnerStruct` is not generated for `SimpleContract`, the bindings use
+    // `shared_types::InnerStruct` instead.
+    abigen!(
+        SimpleContract,
+        r#"
+        {
+            "types": [
+              {
+                "typeId": 0,
+                "type": "()",
+                "components": [],
+                "typeParameters": null
+              },
+              {
+                "typeId": 1,
+                "type": "bool",
+                "components": null,
+                "typeParameters": null
+              },
+              {
+                "typeId": 2,
+                "type": "struct InnerStruct",
+                "components": [
+                  {
+                    "name": "a",
+                    "type": 1,
+                    "typeArguments": null
+                  }
+                ],
+                "typeParameters": null
+              },
+              {
+                "typeId": 3,
+                "type": "struct MyNestedStruct",
+                "components": [
+                  {
+                    "name": "x",
+                    "type": 4,
+                    "typeArguments": null
+                  },
+                  {
+                    "name": "foo",
+                    "type": 2,
+                    "typeArguments": null
+                  }
+                ],
+                "typeParameters": null
+              },
+              {
+                "typeId": 4,
+                "type": "u16",
+                "components": null,
+                "typeParameters": null
+              }
+            ],
+            "functions": [
+              {
+                "inputs": [
+                  {
+                    "name": "top_value",
+                    "type": 3,
+                    "typeArguments": null
+                  }
+                ],
+                "name": "takes_nested_struct",
+                "output": {
+                  "name": "",
+                  "type": 0,
+                  "typeArguments": null
+                }
+              }
+            ]
+          }
+        "#,
+        types = {
+            struct InnerStruct => crate::shared_types::InnerStruct,
+        }
+    );
+
+    let input = MyNestedStruct {
+        x: 10,
+        foo: shared_types::InnerStruct { a: true },
+    };
+
+    let wallet = launch_provider_and_get_wallet().await;
+
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+
+    let call_handler = contract_instance.methods().takes_nested_struct(input);
+
+    let encoded_args = call_handler.contract_call.encoded_args.resolve(0);
+    let encoded = format!(
+        "{}{}",
+        hex::encode(call_handler.contract_call.encoded_selector),
+        hex::encode(&encoded_args)
+    );
+
+    assert_eq!("0000000088bf8a1b000000000000000a0000000000000001", encoded);
+}
+
+mod simple_contract_abi {
+    use fuels::prelude::*;
+
+    abigen!(
+        SimpleContract,
+        "packages/fuels/tests/bindings/takes_ints_returns_bool-abi.json",
+        abi_trait = SimpleContractAbi,
+    );
+}
+
+#[tokio::test]
+async fn compile_bindings_with_shared_abi_trait() {
+    // `OtherSimpleContract` implements the trait generated for `SimpleContract`
+    // instead of defining its own.
+    abigen!(
+        OtherSimpleContract,
+        "packages/fuels/tests/bindings/takes_ints_returns_bool-abi.json",
+        impl_abi_trait = crate::simple_contract_abi::SimpleContractAbi,
+    );
+    use simple_contract_abi::{SimpleContract, SimpleContractAbi};
+
+    fn encoded_call(contract_instance: &impl SimpleContractAbi) -> String {
+        let call_handler = contract_instance.takes_ints_returns_bool(42);
+
+        let encoded_args = call_handler.contract_call.encoded_args.resolve(0);
+        format!(
+            "{}{}",
+            hex::encode(call_handler.contract_call.encoded_selector),
+            hex::encode(&encoded_args)
+        )
+    }
+
+    let wallet = launch_provider_and_get_wallet().await;
+
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet.clone());
+    let other_contract_instance = OtherSimpleContract::new(null_contract_id(), wallet);
+
+    assert_eq!(
+        "000000009593586c000000000000002a",
+        encoded_call(&contract_instance)
+    );
+    assert_eq!(
+        "000000009593586c000000000000002a",
+        encoded_call(&other_contract_instance)
+    );
+}
+
+#[tokio::test]
+async fn mocked_bindings_answer_calls_without_a_node() -> Result<(), Error> {
+    abigen!(
+        MockedContract,
+        r#"
+        {
+            "types": [
+              {
+                "typeId": 0,
+                "type": "bool",
+                "components": null,
+                "typeParameters": null
+              },
+              {
+                "typeId": 1,
+                "type": "struct Point",
+                "components": [
+                  {
+                    "name": "x",
+                    "type": 2,
+                    "typeArguments": null
+                  },
+                  {
+                    "name": "y",
+                    "type": 2,
+                    "typeArguments": null
+                  }
+                ],
+                "typeParameters": null
+              },
+              {
+                "typeId": 2,
+                "type": "u64",
+                "components": null,
+                "typeParameters": null
+              }
+            ],
+            "functions": [
+              {
+                "inputs": [
+                  {
+                    "name": "value",
+                    "type": 2,
+                    "typeArguments": null
+                  }
+                ],
+                "name": "is_even",
+                "output": {
+                  "name": "",
+                  "type": 0,
+                  "typeArguments": null
+                }
+              },
+              {
+                "inputs": [
+                  {
+                    "name": "x",
+                    "type": 2,
+                    "typeArguments": null
+                  }
+                ],
+                "name": "get_point",
+                "output": {
+                  "name": "",
+                  "type": 1,
+                  "typeArguments": null
+                }
+              }
+            ],
+            "loggedTypes": [
+              {
+                "logId": 0,
+                "loggedType": {
+                  "name": "",
+                  "type": 2,
+                  "typeArguments": null
+                }
+              }
+            ]
+          }
+        "#,
+    );
+
+    // ANCHOR: mock_contract
+    let mock = MockedContractMock::new(null_contract_id());
+    mock.expect_is_even(2).returns(true)?;
+    mock.expect_is_even(3).with_log(3u64).reverts(0)?;
+    mock.expect_get_point(0)
+        .any_args()
+        .times(1)
+        .returns(Point { x: 1, y: 2 })?;
+
+    let contract_methods = mock.instance().methods();
+
+    let response = contract_methods.is_even(2).call().await?;
+    assert!(response.value);
+
+    let response = contract_methods.get_point(5).simulate().await?;
+    assert_eq!(response.value, Point { x: 1, y: 2 });
+    mock.verify()?;
+    // ANCHOR_END: mock_contract
+
+    // The revert reason is decoded from the log, as for a real call
+    let err = contract_methods
+        .is_even(3)
+        .call()
+        .await
+        .expect_err("should have reverted");
+    assert!(matches!(err, Error::RevertTransactionError(reason, _) if reason == "3"));
+
+    let err = contract_methods
+        .is_even(4)
+        .call()
+        .await
+        .expect_err("should have failed since no expectation matches");
+    assert!(matches!(err, Error::MockError(msg) if msg.contains("`is_even`")));
+
+    let err = contract_methods
+        .get_point(5)
+        .call()
+        .await
+        .expect_err("should have failed since `get_point` was expected once");
+    assert!(matches!(err, Error::MockError(_)));
+
+    let err = contract_methods
+        .is_even(2)
+        .submit()
+        .await
+        .expect_err("should have failed since there is no transaction to submit");
+    assert!(matches!(err, Error::MockError(_)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn compile_predicate_bindings_with_typed_data() -> Result<(), Error> {
+    // ANCHOR: predicate_abigen
+    predicate_abigen!(
+        ValidationPredicate,
+        r#"
+        {
+            "types": [
+              {
+                "typeId": 0,
+                "type": "bool",
+                "components": null,
+                "typeParameters": null
+              },
+              {
+                "typeId": 1,
+                "type": "u64",
+                "components": null,
+                "typeParameters": null
+              }
+            ],
+            "functions": [
+              {
+                "inputs": [
+                  {
+                    "name": "has_account",
+                    "type": 0,
+                    "typeArguments": null
+                  },
+                  {
+                    "name": "total_complete",
+                    "type": 1,
+                    "typeArguments": null
+                  }
+                ],
+                "name": "main",
+                "output": {
+                  "name": "",
+                  "type": 0,
+                  "typeArguments": null
+                }
+              }
+            ],
+            "loggedTypes": []
+        }
+        "#,
+    );
+
+    let code = vec![0x47, 0x00, 0x00, 0x00];
+    let predicate = ValidationPredicate::new(code.clone());
+
+    let predicate_data = predicate.encode_data(true, 100)?;
+    // ANCHOR_END: predicate_abigen
+
+    let expected_data = ABIEncoder::encode(&[true.into_token(), 100_u64.into_token()])?.resolve(0);
+    assert_eq!(predicate_data, expected_data);
+    assert_eq!(predicate.address(), Predicate::new(code.clone()).address());
+    assert_eq!(predicate.code(), code);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn configurables_are_written_into_the_predicate_code() -> Result<(), Error> {
+    predicate_abigen!(
+        OwnedPredicate,
+        r#"
+        {
+            "types": [
+              {
+                "typeId": 0,
+                "type": "bool",
+                "components": null,
+                "typeParameters": null
+              },
+              {
+                "typeId": 1,
+                "type": "struct Address",
+                "components": [
+                  {
+                    "name": "value",
+                    "type": 2,
+                    "typeArguments": null
+                  }
+                ],
+                "typeParameters": null
+              },
+              {
+                "typeId": 2,
+                "type": "b256",
+                "components": null,
+                "typeParameters": null
+              },
+              {
+                "typeId": 3,
+                "type": "u64",
+                "components": null,
+                "typeParameters": null
+              }
+            ],
+            "functions": [
+              {
+                "inputs": [],
+                "name": "main",
+                "output": {
+                  "name": "",
+                  "type": 0,
+                  "typeArguments": null
+                }
+              }
+            ],
+            "loggedTypes": [],
+            "configurables": [
+              {
+                "name": "OWNER",
+                "configurableType": {
+                  "name": "",
+                  "type": 1,
+                  "typeArguments": null
+                },
+                "offset": 8
+              },
+              {
+                "name": "THRESHOLD",
+                "configurableType": {
+                  "name": "",
+                  "type": 3,
+                  "typeArguments": null
+                },
+                "offset": 40
+              }
+            ]
+        }
+        "#,
+    );
+
+    let code = vec![0; 48];
+    let owner = Address::new([1; 32]);
+
+    // ANCHOR: predicate_configurables
+    let configurables = OwnedPredicate::configurables()
+        .with_owner(owner)?
+        .with_threshold(7)?;
+    let predicate = OwnedPredicate::new(code.clone()).with_configurables(configurables)?;
+    // ANCHOR_END: predicate_configurables
+
+    let mut expected_code = code;
+    expected_code[8..40].copy_from_slice(&[1; 32]);
+    expected_code[40..].copy_from_slice(&7_u64.to_be_bytes());
+    assert_eq!(predicate.code(), expected_code);
+    assert_eq!(predicate.address(), Predicate::new(expected_code).address());
+
+    let err = OwnedPredicate::new(vec![0; 16])
+        .with_configurables(OwnedPredicate::configurables().with_threshold(7)?)
+        .expect_err("should fail");
+    assert!(err.to_string().contains("doesn't fit"));
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
-use fuels::prelude::*;
-use std::future::Future;
+use fuels::{client::types::TransactionStatus, prelude::*};
+use std::{future::Future, time::Duration};
 
 #[tokio::test]
 async fn test_multiple_args() -> Result<(), Error> {
@@ -24,6 +24,50 @@ async fn test_multiple_args() -> Result<(), Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_abigen_from_forc_project() -> Result<(), Error> {
+    // The project path is relative to the crate's `Cargo.toml`
+    abigen!(MyContract, project = "tests/contracts/contract_test");
+
+    let wallet = launch_provider_and_get_wallet().await;
+
+    let contract_id = Contract::deploy(
+        MyContract::BIN_PATH,
+        &wallet,
+        TxParameters::default(),
+        StorageConfiguration::with_storage_path(Some(MyContract::STORAGE_SLOTS_PATH.to_string())),
+    )
+    .await?;
+
+    let contract_instance = MyContract::new(contract_id, wallet);
+
+    let response = contract_instance.methods().get_single(5).call().await?;
+    assert_eq!(response.value, 5);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generated_deploy_functions() -> Result<(), Error> {
+    abigen!(
+        MyContract,
+        project = "tests/contracts/contract_test",
+        embed_bytecode = true
+    );
+
+    let wallet = launch_provider_and_get_wallet().await;
+
+    let salt = Salt::from([1u8; 32]);
+    let expected_contract_id = MyContract::contract_id(&DeployConfig::default(), salt)?;
+
+    let contract_instance =
+        MyContract::deploy_with_salt(&wallet, DeployConfig::default(), salt).await?;
+    assert_eq!(contract_instance.get_contract_id(), &expected_contract_id);
+
+    let response = contract_instance.methods().get_single(5).call().await?;
+    assert_eq!(response.value, 5);
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_contract_calling_contract() -> Result<(), Error> {
     // Tests a contract call that calls another contract (FooCaller calls FooContract underneath)
@@ -129,6 +173,49 @@ async fn test_multi_call() -> Result<(), Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn submitted_calls_are_tracked_until_committed() -> Result<(), Error> {
+    let config = WalletsConfig::new(Some(3), Some(1), Some(DEFAULT_COIN_AMOUNT));
+    let wallets = launch_custom_provider_and_get_wallets(config, None, None).await;
+    let wallet = wallets[0].clone();
+
+    setup_contract_test!(
+        contract_instance,
+        None,
+        "packages/fuels/tests/contracts/contract_test"
+    );
+
+    // ANCHOR: submit_calls
+    // each wallet pays for its own call, so that the calls don't spend the same coins
+    let mut submitted_calls = vec![];
+    for (value, wallet) in (1..).zip(&wallets) {
+        let submitted_call = contract_instance
+            .with_wallet(wallet.clone())?
+            .methods()
+            .initialize_counter(value)
+            .submit()
+            .await?;
+        submitted_calls.push(submitted_call);
+    }
+
+    for (value, submitted_call) in (1..).zip(&submitted_calls) {
+        let response = submitted_call
+            .response(Some(Duration::from_secs(10)))
+            .await?;
+        assert_eq!(response.value, value);
+    }
+    // ANCHOR_END: submit_calls
+
+    let pending_transaction = &submitted_calls[0].pending_transaction;
+    assert!(matches!(
+        pending_transaction.status().await?,
+        TransactionStatus::Success { .. }
+    ));
+    assert!(!pending_transaction.receipts().await?.is_empty());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_contract_call_fee_estimation() -> Result<(), Error> {
     setup_contract_test!(
@@ -380,6 +467,69 @@ async fn test_contract_setup_macro_deploy_with_salt() -> Result<(), Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_contract_setup_macro_commands() -> Result<(), Error> {
+    // ANCHOR: contract_setup_macro_commands
+    setup_contract_test!(
+        Wallets(wallet, other_wallet),
+        Abigen(
+            Contract(name = FooContract, project = "tests/contracts/foo_contract"),
+            Contract(
+                name = FooCallerContract,
+                project = "tests/contracts/foo_caller_contract"
+            ),
+            Script(
+                name = MyScript,
+                project = "tests/scripts/script_with_arguments"
+            ),
+        ),
+        Deploy(
+            name = foo_contract_instance,
+            contract = FooContract,
+            wallet = wallet
+        ),
+        Deploy(
+            name = foo_caller_contract_instance,
+            contract = FooCallerContract,
+            wallet = other_wallet,
+            salt = [1; 32]
+        ),
+        LoadScript(name = script_instance, script = MyScript, wallet = wallet),
+        LoadPredicate(
+            name = predicate,
+            project = "tests/predicates/predicate_true"
+        ),
+    );
+
+    let foo_contract_id = foo_contract_instance.get_contract_id();
+    let res = foo_caller_contract_instance
+        .methods()
+        .call_foo_contract(Bits256(*foo_contract_id.hash()), true)
+        .set_contracts(&[foo_contract_id.clone()])
+        .call()
+        .await?;
+    assert!(res.value);
+
+    let result = script_instance
+        .main(
+            Bimbam { val: 90 },
+            SugarySnack {
+                twix: 100,
+                mars: 1000,
+            },
+        )?
+        .call()
+        .await?;
+    assert_eq!(result.value, Bimbam { val: 2190 });
+    // ANCHOR_END: contract_setup_macro_commands
+
+    let expected_predicate =
+        Predicate::load_from("tests/predicates/predicate_true/out/debug/predicate_true.bin")?;
+    assert_eq!(predicate.address(), expected_predicate.address());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_wallet_getter() -> Result<(), Error> {
     setup_contract_test!(
@@ -58,6 +58,56 @@ async fn test_parse_logs_values() -> Result<(), Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn contract_events_are_read_from_blocks_and_resumed() -> Result<(), Error> {
+    setup_contract_test!(
+        contract_instance,
+        wallet,
+        "packages/fuels/tests/logs/logged_types"
+    );
+
+    let contract_methods = contract_instance.methods();
+    let mut tx_ids = vec![];
+    for _ in 0..2 {
+        let submitted_call = contract_methods.produce_logs_values().submit().await?;
+        submitted_call.response(None).await?;
+        tx_ids.push(submitted_call.pending_transaction.tx_id());
+    }
+
+    // ANCHOR: contract_event_stream
+    let provider = wallet.get_provider()?.clone();
+    let mut events = ContractEventStream::<u64>::new(
+        provider.clone(),
+        contract_instance.get_contract_id(),
+        contract_instance.log_decoder(),
+        EventCursor::default(),
+    );
+
+    let first_event = events.next().await?.expect("has an event");
+    // the cursor can be persisted, to resume reading the events later on
+    let cursor = events.cursor();
+    // ANCHOR_END: contract_event_stream
+
+    assert_eq!(first_event.event, 64);
+    assert_eq!(first_event.tx_id, tx_ids[0]);
+
+    let mut resumed_events = ContractEventStream::<u64>::new(
+        provider,
+        contract_instance.get_contract_id(),
+        contract_instance.log_decoder(),
+        cursor,
+    );
+
+    let second_event = resumed_events.next().await?.expect("has an event");
+    assert_eq!(second_event.event, 64);
+    assert_eq!(second_event.tx_id, tx_ids[1]);
+    assert!(second_event.block_height > first_event.block_height);
+
+    assert!(resumed_events.next().await?.is_none());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_parse_logs_custom_types() -> Result<(), Error> {
     setup_contract_test!(
@@ -81,7 +131,7 @@ async fn test_parse_logs_custom_types() -> Result<(), Error> {
         field_2: expected_bits256,
         field_3: 64,
     };
-    let expected_enum = TestEnum::VariantTwo();
+    let expected_enum = TestEnum::VariantTwo;
 
     assert_eq!(log_test_struct, vec![expected_struct]);
     assert_eq!(log_test_enum, vec![expected_enum]);
@@ -157,7 +207,7 @@ async fn test_get_logs() -> Result<(), Error> {
         field_2: expected_bits256,
         field_3: 64,
     };
-    let expected_enum = TestEnum::VariantTwo();
+    let expected_enum = TestEnum::VariantTwo;
     let expected_generic_struct = StructWithGeneric {
         field_1: expected_struct.clone(),
         field_2: 64,
@@ -3,11 +3,16 @@ use fuel_core::service::{Config as CoreConfig, FuelService};
 use fuel_core_interfaces::model::Message;
 use fuel_gql_client::{client::schema::message::Message as OtherMessage, fuel_tx::Receipt};
 use fuels::{
-    client::{PageDirection, PaginationRequest},
+    client::{types::TransactionStatus, PageDirection, PaginationRequest},
     prelude::*,
+    tx::{
+        field::{GasLimit, GasPrice},
+        Transaction,
+    },
 };
 use fuels_signers::fuel_crypto::SecretKey;
 use fuels_types::block::Block;
+use futures::StreamExt;
 use std::{iter, str::FromStr};
 
 #[tokio::test]
@@ -460,6 +465,168 @@ async fn test_gas_errors() -> Result<(), Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn gas_limit_is_estimated_in_auto_mode() -> Result<(), Error> {
+    setup_contract_test!(
+        contract_instance,
+        wallet,
+        "packages/fuels/tests/contracts/contract_test"
+    );
+    let contract_methods = contract_instance.methods();
+
+    // ANCHOR: auto_gas_limit
+    // the gas limit of the parameters is too low, it is replaced by the estimated one
+    let response = contract_methods
+        .initialize_counter(42)
+        .tx_params(TxParameters::new(None, Some(100), None))
+        .auto_gas_limit(None)
+        .call()
+        .await?;
+    // ANCHOR_END: auto_gas_limit
+    assert_eq!(response.value, 42);
+
+    let tolerance = 0.5;
+    let call_handler = contract_methods
+        .initialize_counter(42)
+        .auto_gas_limit(Some(tolerance));
+    let gas_used = call_handler
+        .estimate_transaction_cost(Some(0.0))
+        .await?
+        .gas_used;
+    let gas_limit = *call_handler.get_executable_call().await?.tx.gas_limit();
+    assert!(gas_limit >= (gas_used as f64 * (1.0 + tolerance)) as u64);
+    assert!(gas_limit < MAX_GAS_PER_TX);
+
+    let mut multi_call_handler = MultiContractCallHandler::new(wallet.clone());
+    multi_call_handler
+        .add_call(contract_methods.initialize_counter(42))
+        .add_call(contract_methods.get_array([42; 2]))
+        .tx_params(TxParameters::new(None, Some(100), None))
+        .auto_gas_limit(None);
+
+    let (counter, array): (u64, [u64; 2]) = multi_call_handler.call().await?.value;
+    assert_eq!(counter, 42);
+    assert_eq!(array, [42; 2]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn gas_price_strategy_prices_transactions_without_gas_price() -> Result<(), Error> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(wallet.address(), BASE_ASSET_ID, 1, 1_000_000);
+    let (provider, _) = setup_test_provider(coins, vec![], None, None).await;
+
+    // ANCHOR: gas_price_strategy
+    let provider = provider.with_gas_price_strategy(FixedGasPrice(1));
+    wallet.set_provider(provider.clone());
+    // ANCHOR_END: gas_price_strategy
+
+    setup_contract_test!(
+        contract_instance,
+        None,
+        "packages/fuels/tests/contracts/contract_test"
+    );
+    let contract_methods = contract_instance.methods();
+
+    let tx = contract_methods
+        .initialize_counter(42)
+        .get_executable_call()
+        .await?
+        .tx;
+    assert_eq!(*tx.gas_price(), 1);
+
+    // an explicit gas price is kept
+    contract_methods
+        .initialize_counter(42)
+        .tx_params(TxParameters::new(Some(3), None, None))
+        .call()
+        .await?;
+
+    // even when it is 0
+    let tx = contract_methods
+        .initialize_counter(42)
+        .tx_params(TxParameters::new(Some(0), None, None))
+        .get_executable_call()
+        .await?
+        .tx;
+    assert_eq!(*tx.gas_price(), 0);
+
+    let (tx_id, _) = wallet
+        .transfer(wallet.address(), 1, BASE_ASSET_ID, TxParameters::default())
+        .await?;
+    let transfer_tx = provider.get_transaction_by_id(&tx_id).await?.transaction;
+    assert!(matches!(transfer_tx, Transaction::Script(tx) if *tx.gas_price() == 1));
+
+    // as are the transfers to contracts
+    let (tx_id, _) = wallet
+        .force_transfer_to_contract(
+            contract_instance.get_contract_id(),
+            1,
+            BASE_ASSET_ID,
+            TxParameters::default(),
+        )
+        .await?;
+    let transfer_tx = provider.get_transaction_by_id(&tx_id).await?.transaction;
+    assert!(matches!(transfer_tx, Transaction::Script(tx) if *tx.gas_price() == 1));
+
+    // the highest gas price paid in the recent blocks
+    let strategy = RecentBlocksPercentile {
+        blocks: 10,
+        percentile: 100,
+    };
+    assert_eq!(strategy.gas_price(&provider).await?, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_follow_transactions_and_new_blocks() -> Result<(), Error> {
+    let config = Config {
+        manual_blocks_enabled: true, // Necessary so the `produce_blocks` API can be used locally
+        ..Config::local_node()
+    };
+    let wallets =
+        launch_custom_provider_and_get_wallets(WalletsConfig::default(), Some(config), None).await;
+    let wallet = &wallets[0];
+    let provider = wallet.get_provider()?;
+
+    // ANCHOR: subscribe_new_blocks
+    let mut new_blocks = provider.subscribe_new_blocks().await?;
+
+    provider.produce_blocks(2, None).await?;
+
+    let first_block = new_blocks.next().await.expect("stream is not over")?;
+    let second_block = new_blocks.next().await.expect("stream is not over")?;
+    // ANCHOR_END: subscribe_new_blocks
+    assert_eq!(first_block.header().height(), 1);
+    assert_eq!(second_block.header().height(), 2);
+
+    let (tx_id, _) = wallet
+        .transfer(wallet.address(), 1, BASE_ASSET_ID, TxParameters::default())
+        .await?;
+
+    // ANCHOR: subscribe_transaction_status
+    let statuses = provider.subscribe_transaction_status(&tx_id).await?;
+    let statuses: Vec<_> = statuses.collect().await;
+    // ANCHOR_END: subscribe_transaction_status
+    assert!(matches!(
+        statuses.as_slice(),
+        [Ok(TransactionStatus::Success { .. })]
+    ));
+
+    let statuses: Vec<_> = provider
+        .poll_transaction_status(&tx_id, DEFAULT_POLLING_INTERVAL)
+        .collect()
+        .await;
+    assert!(matches!(
+        statuses.as_slice(),
+        [Ok(TransactionStatus::Success { .. })]
+    ));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_call_param_gas_errors() -> Result<(), Error> {
     setup_contract_test!(
@@ -1,4 +1,5 @@
 use fuels::prelude::*;
+use fuels::tx::Output;
 
 #[tokio::test]
 async fn test_transaction_script_workflow() -> Result<(), Error> {
@@ -68,13 +69,173 @@ async fn main_function_arguments() -> Result<(), Error> {
         twix: 100,
         mars: 1000,
     };
-    let result = instance.main(bim, bam).call().await?;
+    let result = instance.main(bim, bam)?.call().await?;
     let expected = Bimbam { val: 2190 };
     assert_eq!(result.value, expected);
     // ANCHOR_END: script_with_arguments
     Ok(())
 }
 
+#[tokio::test]
+async fn main_function_with_embedded_binary() -> Result<(), Error> {
+    // ANCHOR: script_embedded_binary
+    script_abigen!(
+        MyScript,
+        project = "tests/scripts/script_with_arguments",
+        embed_bytecode = true
+    );
+    let wallet = launch_provider_and_get_wallet().await;
+    // The binary is part of the test executable, no file is read at runtime
+    let instance = MyScript::load(wallet.clone());
+    // ANCHOR_END: script_embedded_binary
+
+    let bim = Bimbam { val: 90 };
+    let bam = SugarySnack {
+        twix: 100,
+        mars: 1000,
+    };
+    let result = instance.main(bim.clone(), bam.clone())?.call().await?;
+    assert_eq!(result.value, Bimbam { val: 2190 });
+
+    let instance = MyScript::from_binary(wallet, MyScript::BYTECODE.to_vec());
+    let result = instance.main(bim, bam)?.call().await?;
+    assert_eq!(result.value, Bimbam { val: 2190 });
+    Ok(())
+}
+
+#[tokio::test]
+async fn main_function_vector_arguments() -> Result<(), Error> {
+    // ANCHOR: script_vector_arguments
+    script_abigen!(
+        MyScript,
+        "packages/fuels/tests/scripts/script_vectors/out/debug/script_vectors-abi.json"
+    );
+    let wallet = launch_provider_and_get_wallet().await;
+    let bin_path = "../fuels/tests/scripts/script_vectors/out/debug/script_vectors.bin";
+    let instance = MyScript::new(wallet, bin_path);
+
+    let u32_vec = vec![0, 1, 2];
+    let vec_in_vec = vec![vec![0, 1, 2], vec![0, 1, 2]];
+    let vec_in_struct = SomeStruct { a: vec![0, 1, 2] };
+
+    let result = instance
+        .main(u32_vec, vec_in_vec, vec_in_struct)?
+        .call()
+        .await?;
+    // ANCHOR_END: script_vector_arguments
+    assert!(result.value);
+    Ok(())
+}
+
+#[tokio::test]
+async fn script_call_dependencies_are_estimated() -> Result<(), Error> {
+    script_abigen!(
+        MyScript,
+        "packages/fuels/tests/scripts/script_calls_contract/out/debug/script_calls_contract-abi.json"
+    );
+    let wallet = launch_provider_and_get_wallet().await;
+    let contract_id = Contract::deploy(
+        "tests/contracts/token_ops/out/debug/token_ops.bin",
+        &wallet,
+        TxParameters::default(),
+        StorageConfiguration::default(),
+    )
+    .await?;
+    let bin_path =
+        "../fuels/tests/scripts/script_calls_contract/out/debug/script_calls_contract.bin";
+    let instance = MyScript::new(wallet.clone(), bin_path);
+
+    let token_contract: ContractId = (&contract_id).into();
+    let recipient: Address = wallet.address().into();
+    let amount = 100;
+
+    // Fails without the contract input and the variable output
+    let response = instance
+        .main(token_contract, recipient, amount)?
+        .call()
+        .await;
+    assert!(matches!(response, Err(Error::RevertTransactionError(..))));
+
+    // ANCHOR: script_tx_dependencies
+    let response = instance
+        .main(token_contract, recipient, amount)?
+        .estimate_tx_dependencies(None)
+        .await?
+        .call()
+        .await?;
+    // ANCHOR_END: script_tx_dependencies
+    assert_eq!(response.value, amount);
+
+    let response = instance
+        .main(token_contract, recipient, amount)?
+        .set_contracts(&[contract_id])
+        .append_variable_outputs(1)
+        .call()
+        .await?;
+    assert_eq!(response.value, amount);
+
+    let balance = wallet
+        .get_asset_balance(&AssetId::from(*token_contract))
+        .await?;
+    assert_eq!(balance, 2 * amount);
+
+    // The value logged by the failing `require` is decoded as the revert reason
+    let response = instance.main(token_contract, recipient, 0)?.call().await;
+    assert!(matches!(response, Err(Error::RevertTransactionError(reason, _)) if reason == "0"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn script_transactions_are_signed() -> Result<(), Error> {
+    script_abigen!(
+        MyScript,
+        "packages/fuels/tests/scripts/basic_script/out/debug/basic_script-abi.json"
+    );
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(Some(2), Some(1), Some(1000)),
+        Some(Config {
+            utxo_validation: true,
+            ..Config::local_node()
+        }),
+        None,
+    )
+    .await;
+    let other_wallet = wallets.pop().unwrap();
+    let wallet = wallets.pop().unwrap();
+    let bin_path = "../fuels/tests/scripts/basic_script/out/debug/basic_script.bin";
+    let instance = MyScript::new(wallet, bin_path);
+
+    let response = instance.main(1, 2)?.call().await?;
+    assert_eq!(response.value, "hello");
+
+    // ANCHOR: script_with_signers
+    // The inputs of the first added signer use the witness index 1
+    let inputs = other_wallet
+        .get_asset_inputs_for_amount(BASE_ASSET_ID, 1, 1)
+        .await?;
+    // The base asset of the inputs that isn't sent is paid back to the signer
+    let input_amount: u64 = inputs.iter().filter_map(|input| input.amount()).sum();
+    let outputs = vec![Output::coin(
+        other_wallet.address().into(),
+        input_amount,
+        BASE_ASSET_ID,
+    )];
+    let response = instance
+        .main(2, 1)?
+        .with_inputs(inputs)
+        .with_outputs(outputs)
+        .add_signer(other_wallet.clone())
+        .call()
+        .await?;
+    // ANCHOR_END: script_with_signers
+    assert_eq!(response.value, "heyoo");
+    assert_eq!(
+        other_wallet.get_asset_balance(&BASE_ASSET_ID).await?,
+        input_amount
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn main_function_generic_arguments() -> Result<(), Error> {
     script_abigen!(
@@ -91,7 +252,7 @@ async fn main_function_generic_arguments() -> Result<(), Error> {
         twix: bam_comp,
         mars: 1000,
     };
-    let result = instance.main(bim.clone(), bam.clone()).call().await?;
+    let result = instance.main(bim.clone(), bam.clone())?.call().await?;
     let expected = (
         GenericSnack {
             twix: GenericBimbam {
@@ -117,12 +278,12 @@ async fn main_function_option_result() -> Result<(), Error> {
         "../fuels/tests/scripts/script_option_result_types/out/debug/script_option_result_types.bin";
     let instance = MyScript::new(wallet, bin_path);
 
-    let result = instance.main(Some(42), None).call().await?;
+    let result = instance.main(Some(42), None)?.call().await?;
     assert_eq!(result.value, Ok(Some(true)));
-    let result = instance.main(Some(987), None).call().await?;
+    let result = instance.main(Some(987), None)?.call().await?;
     assert_eq!(result.value, Ok(None));
     let expected_error = Err(TestError::ZimZam("error".try_into().unwrap()));
-    let result = instance.main(None, Some(987)).call().await?;
+    let result = instance.main(None, Some(987))?.call().await?;
     assert_eq!(result.value, expected_error);
     Ok(())
 }
@@ -148,7 +309,7 @@ async fn main_function_tuple_types() -> Result<(), Error> {
             Bam {
                 bam: "secod".try_into()?,
             },
-        )
+        )?
         .call()
         .await?;
     let expected = (
@@ -183,17 +344,29 @@ async fn test_basic_script_with_tx_parameters() -> Result<(), Error> {
 
     let a = 1000u64;
     let b = 2000u32;
-    let result = instance.main(a, b).call().await?;
+    let result = instance.main(a, b)?.call().await?;
     assert_eq!(result.value, "hello");
     // ANCHOR: script_with_tx_params
     let parameters = TxParameters {
-        gas_price: 1,
+        gas_price: Some(1),
         gas_limit: 10000,
         ..Default::default()
     };
-    let result = instance.main(a, b).tx_params(parameters).call().await?;
+    let result = instance.main(a, b)?.tx_params(parameters).call().await?;
     // ANCHOR_END: script_with_tx_params
     assert_eq!(result.value, "hello");
 
+    let parameters = TxParameters {
+        gas_limit: 1,
+        ..Default::default()
+    };
+    let result = instance
+        .main(a, b)?
+        .tx_params(parameters)
+        .auto_gas_limit(None)
+        .call()
+        .await?;
+    assert_eq!(result.value, "hello");
+
     Ok(())
 }
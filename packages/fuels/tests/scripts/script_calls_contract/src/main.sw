@@ -0,0 +1,16 @@
+script;
+
+abi TestFuelCoin {
+    fn mint_coins(mint_amount: u64);
+    fn transfer_coins_to_output(coins: u64, asset_id: ContractId, recipient: Address);
+}
+
+fn main(token_contract: ContractId, recipient: Address, amount: u64) -> u64 {
+    require(amount != 0, amount);
+
+    let token = abi(TestFuelCoin, token_contract.into());
+    token.mint_coins(amount);
+    token.transfer_coins_to_output(amount, token_contract, recipient);
+
+    amount
+}
@@ -0,0 +1,40 @@
+script;
+
+struct SomeStruct<T> {
+    a: T,
+}
+
+fn vec_from(vals: [u32; 3]) -> Vec<u32> {
+    let mut vec = Vec::new();
+    vec.push(vals[0]);
+    vec.push(vals[1]);
+    vec.push(vals[2]);
+    vec
+}
+
+fn eq_vec(left: Vec<u32>, right: Vec<u32>) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < left.len() {
+        if left.get(i).unwrap() != right.get(i).unwrap() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+fn main(u32_vec: Vec<u32>, vec_in_vec: Vec<Vec<u32>>, vec_in_struct: SomeStruct<Vec<u32>>) -> bool {
+    let expected = vec_from([0, 1, 2]);
+
+    let mut i = 0;
+    let mut vec_in_vec_matches = vec_in_vec.len() == 2;
+    while i < vec_in_vec.len() {
+        vec_in_vec_matches = vec_in_vec_matches && eq_vec(vec_in_vec.get(i).unwrap(), expected);
+        i += 1;
+    }
+
+    eq_vec(u32_vec, expected) && vec_in_vec_matches && eq_vec(vec_in_struct.a, expected)
+}
@@ -1,3 +1,4 @@
+use fuels::client::schema::coin::CoinStatus;
 use fuels::prelude::*;
 
 #[tokio::test]
@@ -63,3 +64,204 @@ async fn test_wallet_balance_api_single_asset() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn transaction_built_with_a_builder_is_funded_and_signed() -> Result<(), Error> {
+    let mut fee_payer = WalletUnlocked::new_random(None);
+    let mut sender = WalletUnlocked::new_random(None);
+    let receiver = WalletUnlocked::new_random(None);
+
+    let asset_id = AssetId::from([1u8; 32]);
+    let mut coins = setup_single_asset_coins(fee_payer.address(), BASE_ASSET_ID, 1, 1_000);
+    coins.extend(setup_single_asset_coins(sender.address(), asset_id, 1, 100));
+
+    let (provider, _) = setup_test_provider(coins, vec![], None, None).await;
+    fee_payer.set_provider(provider.clone());
+    sender.set_provider(provider.clone());
+
+    // ANCHOR: script_tx_builder
+    // the fee payer signs first (witness index 0), the sender second
+    let inputs = sender.get_asset_inputs_for_amount(asset_id, 30, 1).await?;
+    let outputs = sender.get_asset_outputs_for_amount(receiver.address(), asset_id, 30);
+
+    let tx = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxParameters::default())
+        .finalize_with_signers(&fee_payer, 0, &[sender.clone()])
+        .await?;
+
+    provider.send_transaction(&tx).await?;
+    // ANCHOR_END: script_tx_builder
+
+    assert_eq!(
+        provider
+            .get_asset_balance(receiver.address(), asset_id)
+            .await?,
+        30
+    );
+    assert_eq!(sender.get_asset_balance(&asset_id).await?, 70);
+    assert_eq!(sender.get_asset_balance(&BASE_ASSET_ID).await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn wallet_with_coin_selector_spends_the_selected_coins() -> Result<(), Error> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let receiver = WalletUnlocked::new_random(None);
+
+    let coins = [10, 20, 50]
+        .into_iter()
+        .flat_map(|amount| setup_single_asset_coins(wallet.address(), BASE_ASSET_ID, 1, amount))
+        .collect();
+    let (provider, _) = setup_test_provider(coins, vec![], None, None).await;
+    wallet.set_provider(provider);
+
+    // ANCHOR: coin_selector
+    // the coin of exactly 20 is spent, leaving no change
+    wallet.set_coin_selector(ExactMatchPreferred);
+
+    wallet
+        .transfer(
+            receiver.address(),
+            20,
+            BASE_ASSET_ID,
+            TxParameters::default(),
+        )
+        .await?;
+    // ANCHOR_END: coin_selector
+
+    let mut unspent_amounts: Vec<u64> = wallet
+        .get_coins(BASE_ASSET_ID)
+        .await?
+        .into_iter()
+        .filter(|coin| coin.status == CoinStatus::Unspent && coin.amount.0 > 0)
+        .map(|coin| coin.amount.0)
+        .collect();
+    unspent_amounts.sort();
+    assert_eq!(unspent_amounts, vec![10, 50]);
+
+    Ok(())
+}
+
+async fn unspent_amounts(wallet: &WalletUnlocked) -> Result<Vec<u64>, Error> {
+    let mut amounts: Vec<u64> = wallet
+        .get_coins(BASE_ASSET_ID)
+        .await?
+        .into_iter()
+        .filter(|coin| coin.status == CoinStatus::Unspent)
+        .map(|coin| coin.amount.0)
+        .collect();
+    amounts.sort();
+
+    Ok(amounts)
+}
+
+#[tokio::test]
+async fn wallet_consolidates_and_splits_coins() -> Result<(), Error> {
+    let mut wallet = WalletUnlocked::new_random(None);
+
+    let coins = setup_single_asset_coins(wallet.address(), BASE_ASSET_ID, 10, 10);
+    let (provider, _) = setup_test_provider(coins, vec![], None, None).await;
+    wallet.set_provider(provider);
+
+    // ANCHOR: consolidate_coins
+    // 3 transactions merge the 10 coins into 4, then 2 more merge them into 1
+    let sent_txs = wallet
+        .consolidate_coins(BASE_ASSET_ID, 3, TxParameters::default())
+        .await?;
+    // ANCHOR_END: consolidate_coins
+
+    assert_eq!(sent_txs.len(), 5);
+    assert_eq!(unspent_amounts(&wallet).await?, vec![100]);
+
+    // ANCHOR: split_coins
+    // 4 coins of 20 are split off, the change of 20 is left
+    wallet
+        .split_coins(BASE_ASSET_ID, 4, 20, TxParameters::default())
+        .await?;
+    // ANCHOR_END: split_coins
+
+    assert_eq!(unspent_amounts(&wallet).await?, vec![20; 5]);
+
+    for (n, amount_each) in [(0, 20), (4, 0)] {
+        wallet
+            .split_coins(BASE_ASSET_ID, n, amount_each, TxParameters::default())
+            .await
+            .expect_err("should not split into no coins or coins of 0");
+    }
+    assert_eq!(unspent_amounts(&wallet).await?, vec![20; 5]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn wallet_with_resource_tracker_sends_concurrent_transactions() -> Result<(), Error> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let receiver = WalletUnlocked::new_random(None);
+
+    let coins = setup_single_asset_coins(wallet.address(), BASE_ASSET_ID, 4, 100);
+    let (provider, _) = setup_test_provider(coins, vec![], None, None).await;
+    wallet.set_provider(provider.clone());
+
+    // ANCHOR: resource_tracker
+    let tracker = ResourceTracker::new();
+    wallet.set_resource_tracker(tracker.clone());
+
+    // both transfers spend different coins of the wallet
+    let (first_transfer, second_transfer) = futures::join!(
+        wallet.transfer(
+            receiver.address(),
+            10,
+            BASE_ASSET_ID,
+            TxParameters::default()
+        ),
+        wallet.transfer(
+            receiver.address(),
+            20,
+            BASE_ASSET_ID,
+            TxParameters::default()
+        ),
+    );
+    first_transfer?;
+    second_transfer?;
+    // ANCHOR_END: resource_tracker
+
+    assert_eq!(tracker.reserved_count().await, 0);
+    assert_eq!(
+        provider
+            .get_asset_balance(receiver.address(), BASE_ASSET_ID)
+            .await?,
+        30
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn wallet_with_resource_tracker_releases_resources_of_failed_transactions(
+) -> Result<(), Error> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let receiver = WalletUnlocked::new_random(None);
+
+    // the wallet has no base asset to pay the fee with
+    let asset_id = AssetId::new([1; 32]);
+    let coins = setup_single_asset_coins(wallet.address(), asset_id, 2, 100);
+    let (provider, _) = setup_test_provider(coins, vec![], None, None).await;
+    wallet.set_provider(provider);
+
+    let tracker = ResourceTracker::new();
+    wallet.set_resource_tracker(tracker.clone());
+
+    wallet
+        .transfer(
+            receiver.address(),
+            150,
+            asset_id,
+            TxParameters::new(Some(1), None, None),
+        )
+        .await
+        .expect_err("should not be able to pay the fee");
+
+    assert_eq!(tracker.reserved_count().await, 0);
+
+    Ok(())
+}